@@ -0,0 +1,62 @@
+// benches/weather_parse.rs
+//
+// Baseline for the GeoMet JSON parser. The crate is a binary, so the parser
+// module is pulled in by path rather than through a library target.
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+// The modules' own unit tests come along too; they don't run here
+#[allow(dead_code, unused_imports)]
+#[path = "../src/weather/api.rs"]
+mod api;
+
+#[allow(dead_code)]
+#[path = "../src/utils/logger.rs"]
+pub mod logger;
+
+#[allow(dead_code, unused_imports)]
+#[path = "../src/utils/encoding.rs"]
+mod encoding;
+
+#[allow(unused_imports)]
+#[path = "../src/weather/icons.rs"]
+pub mod icons;
+
+#[allow(dead_code, unused_imports)]
+#[path = "../src/weather/models.rs"]
+pub mod models;
+
+// Mirrors the crate paths api.rs imports from
+mod utils {
+    pub use super::encoding::decompress_if_needed;
+    pub use super::logger;
+}
+
+mod weather {
+    pub use super::icons;
+    pub use super::models;
+}
+
+const FIXTURE: &str = include_str!("fixtures/citypageweather_on-143.json");
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("weather_parse");
+    group.throughput(Throughput::Bytes(FIXTURE.len() as u64));
+
+    // JSON decoding on its own, to separate it from field extraction
+    group.bench_function("serde_json_value", |b| {
+        b.iter(|| serde_json::from_str::<serde_json::Value>(black_box(FIXTURE)).unwrap())
+    });
+
+    group.bench_function("parse_api_response", |b| {
+        b.iter(|| api::parse_api_response(black_box(FIXTURE)).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(1000);
+    targets = bench_parse
+}
+criterion_main!(benches);
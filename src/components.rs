@@ -1,8 +1,16 @@
+pub mod air_quality;
+pub mod alert_badge;
 pub mod bin;
+pub mod bus_departures;
 pub mod carousel;
 pub mod clock;
 pub mod dim;
 pub mod location_input;
+pub mod settings_panel;
 pub mod weather;
+pub mod weather_current;
 pub mod weather_daily;
 pub mod weather_hourly;
+pub mod weather_summary_bar;
+pub mod weather_warnings;
+pub mod wind_compass;
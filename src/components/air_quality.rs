@@ -0,0 +1,127 @@
+// src/components/air_quality.rs
+use yew::{function_component, html, Html, Properties};
+use crate::weather::models::AirQuality;
+
+const SPARKLINE_WIDTH: f32 = 60.0;
+const SPARKLINE_HEIGHT: f32 = 16.0;
+// Fewer points than this don't make a line worth drawing
+const SPARKLINE_MIN_POINTS: usize = 3;
+// "Very high risk" has no Bootstrap colour; same purple as the AQHI scale bar
+const AQHI_VERY_HIGH_COLOR: &str = "#8f3f97";
+
+fn aqhi_text_class(index: f32) -> &'static str {
+    match index.round() as u32 {
+        0..=3 => "text-success",
+        4..=6 => "text-warning",
+        _ => "text-danger",
+    }
+}
+
+// Badge classes, plus an inline background for the 10+ band
+fn aqhi_badge_style(index: f32) -> (&'static str, Option<String>) {
+    match index.round() as u32 {
+        0..=3 => ("bg-success", None),
+        4..=6 => ("bg-warning text-dark", None),
+        7..=10 => ("bg-danger", None),
+        _ => ("text-white", Some(format!("background-color: {};", AQHI_VERY_HIGH_COLOR))),
+    }
+}
+
+fn sparkline_points(values: &[u32]) -> String {
+    let min = values.iter().copied().min().unwrap_or(0) as f32;
+    let max = values.iter().copied().max().unwrap_or(0) as f32;
+    let range = max - min;
+    let step = SPARKLINE_WIDTH / (values.len() - 1) as f32;
+
+    values.iter()
+        .enumerate()
+        .map(|(i, &v)| {
+            // A flat trend sits in the middle rather than dividing by zero
+            let y = if range == 0.0 {
+                SPARKLINE_HEIGHT / 2.0
+            } else {
+                SPARKLINE_HEIGHT - (v as f32 - min) / range * SPARKLINE_HEIGHT
+            };
+            format!("{:.1},{:.1}", i as f32 * step, y)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct AirQualityBadgeProps {
+    pub current: Option<AirQuality>,
+    /// Upcoming hourly AQHI values, when the source provides them
+    #[prop_or_default]
+    pub trend: Option<Vec<u32>>,
+}
+
+#[function_component(AirQualityBadge)]
+pub fn air_quality_badge(props: &AirQualityBadgeProps) -> Html {
+    let Some(aq) = props.current.as_ref() else {
+        return html! {};
+    };
+    let (badge_class, badge_style) = aqhi_badge_style(aq.index);
+    let trend = props.trend.as_ref()
+        .filter(|values| values.len() >= SPARKLINE_MIN_POINTS);
+
+    html! {
+        <span class="text-nowrap">
+            {"Air Quality: "}
+            <strong class={aqhi_text_class(aq.index)}>{&aq.category}</strong>{" "}
+            <span class={format!("badge {}", badge_class)} style={badge_style}>{format!("{:.0}", aq.index)}</span>
+            if let Some(values) = trend {
+                <svg
+                    class="ms-1 align-middle"
+                    width={SPARKLINE_WIDTH.to_string()}
+                    height={SPARKLINE_HEIGHT.to_string()}
+                    viewBox={format!("0 0 {} {}", SPARKLINE_WIDTH, SPARKLINE_HEIGHT)}
+                    role="img"
+                    aria-label={format!("AQHI over the next {} hours", values.len())}
+                >
+                    <polyline
+                        points={sparkline_points(values)}
+                        fill="none"
+                        stroke="currentColor"
+                        stroke-width="1.5"
+                    />
+                </svg>
+            }
+        </span>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aqhi_bands_round_to_the_nearest_index() {
+        assert_eq!(aqhi_text_class(3.4), "text-success");
+        assert_eq!(aqhi_text_class(3.5), "text-warning");
+        assert_eq!(aqhi_text_class(6.0), "text-warning");
+        assert_eq!(aqhi_text_class(7.0), "text-danger");
+    }
+
+    #[test]
+    fn only_the_very_high_band_gets_an_inline_colour() {
+        assert_eq!(aqhi_badge_style(2.0), ("bg-success", None));
+        assert_eq!(aqhi_badge_style(5.0), ("bg-warning text-dark", None));
+        assert_eq!(aqhi_badge_style(10.0), ("bg-danger", None));
+        assert_eq!(
+            aqhi_badge_style(11.0),
+            ("text-white", Some(format!("background-color: {};", AQHI_VERY_HIGH_COLOR))),
+        );
+    }
+
+    #[test]
+    fn sparkline_spans_the_full_box() {
+        // Highest value at the top (y = 0), lowest at the bottom
+        assert_eq!(sparkline_points(&[2, 4, 3]), "0.0,16.0 30.0,0.0 60.0,8.0");
+    }
+
+    #[test]
+    fn flat_sparkline_sits_in_the_middle() {
+        assert_eq!(sparkline_points(&[5, 5, 5]), "0.0,8.0 30.0,8.0 60.0,8.0");
+    }
+}
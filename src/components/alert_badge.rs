@@ -0,0 +1,38 @@
+use yew::{function_component, html, Html, Properties};
+
+#[derive(Properties, PartialEq)]
+pub struct AlertBadgeProps {
+    pub count: usize,
+    #[prop_or_default]
+    pub severe: bool,
+    /// Carousel the badge jumps to, e.g. `"#carousel_main"`
+    pub carousel_target: String,
+    #[prop_or_default]
+    pub slide_index: usize,
+}
+
+#[function_component]
+pub fn AlertBadge(props: &AlertBadgeProps) -> Html {
+    if props.count == 0 {
+        return html! {};
+    }
+
+    let severity_class = if props.severe { "alert-badge-severe" } else { "alert-badge-moderate" };
+    let label = match props.count {
+        1 => "1 weather alert".to_string(),
+        n => format!("{} weather alerts", n),
+    };
+
+    // Bootstrap handles the slide change through the data attributes
+    html! {
+        <button
+            type="button"
+            class={format!("alert-badge {} align-self-center", severity_class)}
+            data-bs-target={props.carousel_target.clone()}
+            data-bs-slide-to={props.slide_index.to_string()}
+            aria-label={label}
+        >
+            {props.count}
+        </button>
+    }
+}
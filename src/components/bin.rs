@@ -1,14 +1,34 @@
 use chrono::prelude::*;
-use chrono::{DateTime, Local, Weekday};
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, Weekday};
 use futures_util::StreamExt;
+use gloo_storage::{LocalStorage, Storage};
+use std::marker::PhantomData;
 use std::time::Duration;
-use yew::platform::time::interval;
-use yew::{function_component, html, AttrValue, Component, Context, Html, Properties};
+use yew::platform::time::{interval, sleep};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+use yew::{classes, function_component, html, use_context, AttrValue, Callback, Component, Context, Html, Properties};
 
-use crate::weather::api::WeatherData;
+use crate::context::weather::WeatherContext;
+use crate::utils::ical::{generate_bin_ical, is_garbage_week, next_pickup};
+use crate::utils::logger::{log_bin, LogLevel};
+use crate::utils::temperature::{convert, format_temperature, TemperatureUnit};
+use crate::utils::{SystemTimeProvider, TimeProvider};
+use crate::weather::{WeatherData, WeatherTrend};
 
-const REFRESH_HOURS: u64 = 1;
+const TICK_SECS: u64 = 60;
+// Bins go out early; the hourly forecast is checked for rain either side of this
+const PICKUP_HOUR: u32 = 7;
+const PICKUP_WINDOW_HOURS: i64 = 3;
+const PICKUP_RAIN_POP: u32 = 50;
+// Daily chance of precipitation that flags the whole collection day
+const PICKUP_DAY_RAIN_POP: u32 = 50;
+// Lower bar when pickup is within a day and the hourly trend is worsening
+const PICKUP_DAY_RAIN_POP_WORSENING: u32 = 40;
+// Feels-like (°C) cold enough that bin wheels can freeze to the ice
+const ICY_FEELS_LIKE: f32 = -10.0;
 
+#[derive(Debug, PartialEq)]
 pub enum BinVariation {
     Yellow,
     None,
@@ -28,9 +48,16 @@ const CHRISTMAS_TREE_START_DAY: u32 = 6;
 const CHRISTMAS_TREE_END_MONTH: u32 = 1;
 const CHRISTMAS_TREE_END_DAY: u32 = 29;
 
+pub fn yard_waste_season(year: i32) -> (NaiveDate, NaiveDate) {
+    (
+        NaiveDate::from_ymd_opt(year, YARD_WASTE_START_MONTH, YARD_WASTE_START_DAY).unwrap(),
+        NaiveDate::from_ymd_opt(year, YARD_WASTE_END_MONTH, YARD_WASTE_END_DAY).unwrap(),
+    )
+}
+
 // Check if we're in yard waste season
-pub fn is_yard_waste_season() -> bool {
-    let current = get_today();
+pub fn is_yard_waste_season(time: &impl TimeProvider) -> bool {
+    let current = time.now();
     let year = current.year();
     
     // Toronto yard waste collection season
@@ -40,9 +67,16 @@ pub fn is_yard_waste_season() -> bool {
     current >= season_start && current <= season_end
 }
 
+pub fn christmas_tree_season(year: i32) -> (NaiveDate, NaiveDate) {
+    (
+        NaiveDate::from_ymd_opt(year, CHRISTMAS_TREE_START_MONTH, CHRISTMAS_TREE_START_DAY).unwrap(),
+        NaiveDate::from_ymd_opt(year, CHRISTMAS_TREE_END_MONTH, CHRISTMAS_TREE_END_DAY).unwrap(),
+    )
+}
+
 // Check if we're in Christmas tree collection period
-pub fn is_christmas_tree_season() -> bool {
-    let current = get_today();
+pub fn is_christmas_tree_season(time: &impl TimeProvider) -> bool {
+    let current = time.now();
     let year = current.year();
     
     // Toronto Christmas tree collection (January 6-31)
@@ -52,75 +86,163 @@ pub fn is_christmas_tree_season() -> bool {
     current >= season_start && current <= season_end
 }
 
-// Blue and Black/Brown bins alternate every week (based on 2-week cycle)
-pub fn get_alternate_bin() -> BinVariation {
-    // This date needs to be updated annually to align with the current cycle
-    let known_yellow_bin_day = Local.with_ymd_and_hms(2025, 10, 16, 0, 0, 0).unwrap();
-    let diff = get_today() - known_yellow_bin_day;
-
-    let wat = diff.num_days() % 14;
+// Blue and Black/Brown bins alternate every week; shows the bins for the
+// next pickup, counted from a known recycling-week (blue bin) collection day
+pub fn get_alternate_bin(time: &impl TimeProvider, known_blue_bin_day: NaiveDate, pickup_weekday: Weekday) -> BinVariation {
+    let pickup = next_pickup(time.now().date_naive(), pickup_weekday);
 
-    if wat != 0 && wat <= 7 {
+    if is_garbage_week(known_blue_bin_day, pickup) {
         return BinVariation::Yellow; // Will display Black and Brown bins
     }
-    return BinVariation::None; // Will display Blue bin
+    BinVariation::None // Will display Blue bin
 }
 
-pub fn get_today() -> DateTime<Local> {
-    let current: DateTime<Local> = Local::now();
-    return current;
+// Whole days from `today` until the next `pickup`, 0 if it's today
+pub fn days_until(today: Weekday, pickup: Weekday) -> u32 {
+    (pickup.num_days_from_monday() + 7 - today.num_days_from_monday()) % 7
+}
+
+// From this hour on the day before pickup the reminder to put bins out shows
+const PUT_OUT_FROM_HOUR: u32 = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinStatus {
+    PutOutTonight,
+    PickupToday,
+    DaysAway(u32),
+}
+
+pub fn bin_status(now: DateTime<Local>, pickup: Weekday) -> BinStatus {
+    match days_until(now.weekday(), pickup) {
+        0 => BinStatus::PickupToday,
+        1 if now.hour() >= PUT_OUT_FROM_HOUR => BinStatus::PutOutTonight,
+        days => BinStatus::DaysAway(days),
+    }
 }
 
 #[derive(Properties, PartialEq)]
-pub struct BinComponentProps {
+pub struct BinComponentProps<T: TimeProvider + Clone + Default + PartialEq + 'static = SystemTimeProvider> {
+    /// Where "now" comes from; the system clock unless pinned to a fixed time
+    #[prop_or_default]
+    pub clock: T,
     #[prop_or_default]
     pub weather: Option<WeatherData>,
+    // Compact layouts only want the bins and the countdown
+    #[prop_or(true)]
+    pub show_weather_forecast: bool,
+    #[prop_or(Weekday::Thu)]
+    pub pickup_weekday: Weekday,
+    #[prop_or_default]
+    pub temperature_unit: TemperatureUnit,
+    /// Known recycling-week (blue bin) collection day, see `Settings::cycle_anchor`
+    pub cycle_anchor: NaiveDate,
 }
 
-pub struct BinComponent {
+impl<T: TimeProvider + Clone + Default + PartialEq + 'static> BinComponentProps<T> {
+    /// The weather to flag pickup conditions from; none when the forecast is hidden
+    fn shown_weather(&self) -> Option<&WeatherData> {
+        self.weather.as_ref().filter(|_| self.show_weather_forecast)
+    }
+}
+
+fn millis_until_next_minute_boundary() -> u64 {
+    let now = Local::now();
+    let into_minute = now.second() as u64 * 1000 + now.timestamp_subsec_millis() as u64;
+    60_000 - into_minute.min(59_999)
+}
+
+// Date the "BIN DAY TODAY!!" reminder was last dismissed
+const REMINDER_DISMISSED_KEY: &str = "bin_reminder_dismissed";
+
+fn is_reminder_dismissed(today: NaiveDate) -> bool {
+    LocalStorage::get::<NaiveDate>(REMINDER_DISMISSED_KEY).is_ok_and(|date| date == today)
+}
+
+pub struct BinComponent<T: TimeProvider + Clone + Default + PartialEq + 'static = SystemTimeProvider> {
+    // The clock itself comes in through the props
+    clock: PhantomData<T>,
     current_time: DateTime<Local>,
+    // Only ever true on the day it was dismissed
+    reminder_dismissed: bool,
 }
 
 pub enum BinComponentMsg {
     ClockTicked(DateTime<Local>),
+    // The first tick of a new day
+    MidnightCrossed,
+    DismissReminder,
 }
 
-impl Component for BinComponent {
+impl<T: TimeProvider + Clone + Default + PartialEq + 'static> Component for BinComponent<T> {
     type Message = BinComponentMsg;
-    type Properties = BinComponentProps; 
+    type Properties = BinComponentProps<T>;
 
     fn create(ctx: &Context<Self>) -> Self {
-        let time_steam =
-            interval(Duration::from_secs(60 * 60 * REFRESH_HOURS)).map(|_| get_today());
+        // Line the ticks up with the clock so the day flips right at midnight;
+        // the interval is only created once the first boundary is reached
+        let delay = Duration::from_millis(millis_until_next_minute_boundary());
+        let clock = ctx.props().clock.clone();
+        let time_steam = futures_util::stream::once(sleep(delay))
+            .flat_map(|_| {
+                futures_util::stream::once(async {})
+                    .chain(interval(Duration::from_secs(TICK_SECS)))
+            })
+            .map(move |_| clock.now());
         ctx.link()
             .send_stream(time_steam.map(BinComponentMsg::ClockTicked));
 
+        let current_time = ctx.props().clock.now();
         Self {
-            current_time: get_today(),
+            clock: PhantomData,
+            current_time,
+            reminder_dismissed: is_reminder_dismissed(current_time.date_naive()),
         }
     }
 
-    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             BinComponentMsg::ClockTicked(current_time) => {
+                let pickup_weekday = ctx.props().pickup_weekday;
+                let new_day = current_time.date_naive() != self.current_time.date_naive();
+                let status_changed = bin_status(current_time, pickup_weekday) != bin_status(self.current_time, pickup_weekday);
                 self.current_time = current_time;
+                self.reminder_dismissed = is_reminder_dismissed(current_time.date_naive());
+                if new_day {
+                    ctx.link().send_message(BinComponentMsg::MidnightCrossed);
+                    return false;
+                }
+                // Besides the date, only the evening reminder changes what's shown
+                status_changed
+            }
+            BinComponentMsg::MidnightCrossed => {
+                log_bin(&format!("New day: {}", self.current_time.format("%a %d %b")), LogLevel::Debug);
+                true
+            }
+            BinComponentMsg::DismissReminder => {
+                if let Err(e) = LocalStorage::set(REMINDER_DISMISSED_KEY, self.current_time.date_naive()) {
+                    log_bin(&format!("Failed to save reminder dismissal: {:?}", e), LogLevel::Warn);
+                }
+                self.reminder_dismissed = true;
+                true
             }
         }
-        true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
-        let show_brown_bin = is_yard_waste_season();
-        let show_christmas_tree = is_christmas_tree_season();
+        let unit = ctx.props().temperature_unit;
+        let clock = &ctx.props().clock;
+        let show_brown_bin = is_yard_waste_season(clock);
+        let show_christmas_tree = is_christmas_tree_season(clock);
         
         // Calculate days until pickup
-        let days_until_pickup = (3 + 7 - self.current_time.weekday().num_days_from_monday()) % 7;
-        let days_text = if days_until_pickup == 0 {
-            "Today".to_string()
-        } else if days_until_pickup == 1 {
-            "Tomorrow".to_string()
-        } else {
-            format!("{} days", days_until_pickup)
+        let pickup_weekday = ctx.props().pickup_weekday;
+        let days_until_pickup = days_until(self.current_time.weekday(), pickup_weekday);
+        let status = bin_status(self.current_time, pickup_weekday);
+        let status_text = match status {
+            BinStatus::PickupToday => "BIN DAY TODAY!!".to_string(),
+            BinStatus::PutOutTonight => "Put bins out tonight!".to_string(),
+            BinStatus::DaysAway(1) => "Tomorrow".to_string(),
+            BinStatus::DaysAway(days) => format!("{} days", days),
         };
         
         // Get day name for forecast lookup
@@ -128,85 +250,149 @@ impl Component for BinComponent {
         let day_name = pickup_date.format("%A").to_string(); // "Thursday", "Friday", etc.
         
         // Get forecast for pickup day
-        let forecast = ctx.props().weather.as_ref()
+        let weather = ctx.props().shown_weather();
+        let forecast = weather
             .and_then(|w| w.get_forecast_for_day(&day_name));
         
+        let (rain_on_pickup_day, frost_on_pickup_day) = weather
+            .map_or((false, false), |w| {
+                // The hourly trend only says anything about the next few hours
+                let threshold = if days_until_pickup <= 1 && w.hourly_trend() == WeatherTrend::Worsening {
+                    PICKUP_DAY_RAIN_POP_WORSENING
+                } else {
+                    PICKUP_DAY_RAIN_POP
+                };
+                (w.has_precipitation_risk(&day_name, threshold), w.has_overnight_frost(&day_name))
+            });
+
+        // Hourly data only reaches a day ahead, so this only shows close to pickup
+        let pickup_time = pickup_date.date_naive()
+            .and_hms_opt(PICKUP_HOUR, 0, 0)
+            .and_then(|t| t.and_local_timezone(Local).single());
+        let rain_at_pickup = match (pickup_time, weather) {
+            (Some(pickup_time), Some(weather)) => weather
+                .get_hourly_for_time_range(
+                    pickup_time - chrono::Duration::hours(PICKUP_WINDOW_HOURS),
+                    pickup_time + chrono::Duration::hours(PICKUP_WINDOW_HOURS),
+                )
+                .iter()
+                .map(|f| f.pop)
+                .max()
+                .filter(|&pop| pop >= PICKUP_RAIN_POP),
+            _ => None,
+        };
+
+        // Today's sunrise stands in for pickup day's; it only moves a few minutes a day
+        let pickup_before_sunrise = weather
+            .filter(|_| days_until_pickup <= 1)
+            .and_then(|w| w.sunrise)
+            .zip(NaiveTime::from_hms_opt(PICKUP_HOUR, 0, 0))
+            .is_some_and(|(sunrise, pickup)| pickup < sunrise);
+
         html! {
-            <div class="d-flex align-items-center">
-                // Only Green bin is always displayed
-                <BinImage src="GreenBin.png" alt="Green Bin" />
+            <section class="d-flex align-items-center" aria-label="Waste collection schedule">
+                <ul class="list-unstyled d-flex align-items-center mb-0 p-0" role="list">
+                    // Only Green bin is always displayed
+                    <BinImage src="GreenBin.png" alt="Green Bin — organics, collected every week" list_item=true warning={rain_on_pickup_day} />
 
-                // Alternating Blue vs Black and Brown bins
-                {
-                    match get_alternate_bin() {
-                        BinVariation::Yellow => html! { 
-                            <> 
-                                <BinImage src="GarbageBin.png" alt="Garbage Bin" />
-                                // Brown bin only shown during yard waste season
-                                if show_brown_bin {
-                                    // FIX: Explicitly set height and width to maintain correct aspect ratio on mobile
-                                    <BinImage 
-                                        src="YardWaste.png" 
-                                        alt="Yard Waste" 
-                                        size_style="height: 4rem; width: 2.9rem;"
-                                    />
-                                }
-                            </> 
-                        },
-                        BinVariation::None => html! { <BinImage src="BlueBin.png" alt="Blue Bin" /> }
+                    // Alternating Blue vs Black and Brown bins
+                    {
+                        match get_alternate_bin(clock, ctx.props().cycle_anchor, pickup_weekday) {
+                            BinVariation::Yellow => html! { 
+                                <> 
+                                    <BinImage src="GarbageBin.png" alt="Garbage Bin — garbage, alternating week B" list_item=true warning={rain_on_pickup_day} />
+                                    // Brown bin only shown during yard waste season
+                                    if show_brown_bin {
+                                        // FIX: Explicitly set height and width to maintain correct aspect ratio on mobile
+                                        <BinImage 
+                                            src="YardWaste.png" 
+                                            alt="Yard Waste — leaf and yard waste, alternating week B in season" 
+                                            size_style="height: 4rem; width: 2.9rem;"
+                                            list_item=true
+                                            warning={rain_on_pickup_day}
+                                        />
+                                    }
+                                </> 
+                            },
+                            BinVariation::None => html! { <BinImage src="BlueBin.png" alt="Blue Bin — mixed recycling, alternating week A" list_item=true warning={rain_on_pickup_day} /> }
+                        }
                     }
-                }
 
-                // Christmas tree icon during collection period
-                if show_christmas_tree {
-                    <BinImage src="Christmastree.png" alt="Christmas Tree" />
-                }
+                    // Christmas tree icon during collection period
+                    if show_christmas_tree {
+                        <BinImage src="Christmastree.png" alt="Christmas Tree — tree collection, January only" list_item=true warning={rain_on_pickup_day} />
+                    }
+                </ul>
 
-                <div class="fs-1 fw-bold text-body"> 
-                    if self.current_time.weekday() == Weekday::Thu {
-                        {"BIN DAY TODAY!!"}
-                    } else {
-                        {days_text}
+                if status == BinStatus::PickupToday && self.reminder_dismissed {
+                    <div class="fs-4" role="timer" title="Bin day today">{"🗑️"}</div>
+                } else {
+                    <div
+                        class={classes!("fs-1", "fw-bold", if rain_on_pickup_day { "text-warning" } else { "text-body" })}
+                        role="timer"
+                        aria-live="polite"
+                    >
+                        {status_text}
+                    </div>
+                    if status == BinStatus::PickupToday {
+                        <button
+                            type="button"
+                            class="btn-close ms-2 align-self-start"
+                            aria-label="Dismiss until tomorrow"
+                            onclick={ctx.link().callback(|_| BinComponentMsg::DismissReminder)}
+                        />
                     }
-                </div>
+                }
                 
+                if pickup_before_sunrise {
+                    <div class="ms-3 fs-6 text-body">
+                        {"🌅 Pickup before sunrise — put bins out the night before"}
+                    </div>
+                }
+
+                if let Some(pop) = rain_at_pickup {
+                    <div class="ms-3 fs-6 text-warning" role="alert">
+                        {format!("☔ {}% rain around pickup", pop)}
+                    </div>
+                }
+
                 // Weather info display for pickup day forecast
                 {
                     if let Some(f) = forecast {
                         html! {
-                            <div class="ms-3 text-body">
+                            <aside class="ms-3 text-body" aria-label="Weather forecast for collection day">
                                 <div class="fs-5">
                                     {&f.icon}{" "}{&f.summary}
                                 </div>
                                 {if let (Some(high), Some(low)) = (f.high, f.low) {
                                     html! {
                                         <div class="fs-6">
-                                            {format!("{}°C / {}°C", high, low)}
+                                            {format!("{:.0}° / {}", convert(high as f32, unit), format_temperature(low as f32, unit))}
                                         </div>
                                     }
                                 } else {
                                     html! {}
                                 }}
-                                {if let Some(pop) = f.pop {
-                                    if pop > 50 {
-                                        html! {
-                                            <div class="fs-6 text-warning">
-                                                {"⚠️ "}{format!("{}% rain", pop)}
-                                            </div>
-                                        }
-                                    } else {
-                                        html! {}
-                                    }
-                                } else {
-                                    html! {}
-                                }}
-                            </div>
+                                if let Some(feels_like) = f.pickup_feels_like {
+                                    <div class={classes!("fs-6", if feels_like < ICY_FEELS_LIKE { "text-warning" } else { "text-body" })}>
+                                        {format!("Feels like {} at 8 AM", format_temperature(feels_like, unit))}
+                                    </div>
+                                }
+                                if rain_on_pickup_day {
+                                    <div class="fs-6 text-warning">
+                                        {"⚠️ "}{format!("{}% rain", f.pop.unwrap_or_default())}
+                                    </div>
+                                }
+                                if frost_on_pickup_day {
+                                    <div class="fs-6 text-info">{"❄️ Frost overnight"}</div>
+                                }
+                            </aside>
                         }
                     } else {
                         html! {}
                     }
                 }
-            </div>
+            </section>
         }
     }
 }
@@ -218,10 +404,16 @@ pub struct BinImageProps {
     // NEW: Optional property to inject custom size/style when necessary
     #[prop_or_default]
     pub size_style: AttrValue, 
+    // Wrap the image in an <li> when it sits inside a role="list" row
+    #[prop_or_default]
+    pub list_item: bool,
+    // Rain is forecast for collection day
+    #[prop_or_default]
+    pub warning: bool,
 }
 
 #[function_component]
-fn BinImage(&BinImageProps { ref src, ref alt, ref size_style }: &BinImageProps) -> Html {
+fn BinImage(BinImageProps { src, alt, size_style, list_item, warning }: &BinImageProps) -> Html {
     
     // Base style that applies to all bins
     let base_style = "object-fit: contain; margin-right: 5px; border: none; outline: none; box-shadow: none; background: transparent; padding: 0; display: inline-block; vertical-align: middle;";
@@ -236,12 +428,277 @@ fn BinImage(&BinImageProps { ref src, ref alt, ref size_style }: &BinImageProps)
     };
 
 
-    html! {
+    let image = html! {
         <img 
             class="bin-icon"
             src={src.clone()} 
             alt={alt.clone()} 
             style={final_style} // Use the calculated style
         />
+    };
+    let image = if *warning {
+        html! {
+            <span class="position-relative d-inline-block">
+                {image}
+                <span class="position-absolute top-0 end-0 fs-6" aria-hidden="true">{"⚠️"}</span>
+            </span>
+        }
+    } else {
+        image
+    };
+
+    if *list_item {
+        html! { <li role="listitem" aria-label={alt.clone()}>{image}</li> }
+    } else {
+        image
+    }
+}
+
+// About a season's worth of collection days
+const ICAL_EXPORT_WEEKS: u32 = 26;
+const ICAL_FILE_NAME: &str = "bin-collection.ics";
+
+#[derive(Properties, PartialEq)]
+pub struct ExportIcalButtonProps<T: TimeProvider + Clone + Default + PartialEq + 'static = SystemTimeProvider> {
+    /// Where "now" comes from; the export starts from today's date
+    #[prop_or_default]
+    pub clock: T,
+    #[prop_or(Weekday::Thu)]
+    pub pickup_weekday: Weekday,
+    /// Known recycling-week (blue bin) collection day, see `Settings::cycle_anchor`
+    pub cycle_anchor: NaiveDate,
+}
+
+#[function_component]
+pub fn ExportIcalButton<T: TimeProvider + Clone + Default + PartialEq + 'static = SystemTimeProvider>(props: &ExportIcalButtonProps<T>) -> Html {
+    let onclick = {
+        let pickup_weekday = props.pickup_weekday;
+        let cycle_anchor = props.cycle_anchor;
+        let clock = props.clock.clone();
+        Callback::from(move |_| {
+            let ical = generate_bin_ical(
+                clock.now().date_naive(),
+                ICAL_EXPORT_WEEKS,
+                cycle_anchor,
+                pickup_weekday,
+                yard_waste_season,
+            );
+            if let Err(e) = download_file(ICAL_FILE_NAME, "text/calendar", &ical) {
+                log_bin(&format!("iCal export failed: {:?}", e), LogLevel::Error);
+            }
+        })
+    };
+
+    html! {
+        <button type="button" class="btn btn-outline-primary" {onclick}>
+            {"📅 Export to calendar"}
+        </button>
+    }
+}
+
+// Hands `contents` to the browser as a file download via a temporary object URL
+fn download_file(file_name: &str, mime_type: &str, contents: &str) -> Result<(), JsValue> {
+    let parts = js_sys::Array::of1(&JsValue::from_str(contents));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let anchor: HtmlAnchorElement = gloo::utils::document()
+        .create_element("a")?
+        .dyn_into()?;
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+
+    Url::revoke_object_url(&url)
+}
+
+const SCHEDULE_PREVIEW_WEEKS: i64 = 3;
+// Daily forecasts only name the next week of days
+const FORECAST_DAYS: i64 = 7;
+
+/// The next few pickups from `today` and the bins that go out on each
+fn schedule_preview(today: NaiveDate, pickup_weekday: Weekday, anchor: NaiveDate) -> Vec<(NaiveDate, Vec<&'static str>)> {
+    let first_pickup = next_pickup(today, pickup_weekday);
+
+    (0..SCHEDULE_PREVIEW_WEEKS).map(|week| {
+        let pickup = first_pickup + chrono::Duration::weeks(week);
+        let in_season = |(start, end): (NaiveDate, NaiveDate)| pickup >= start && pickup <= end;
+
+        let mut bins = vec!["Green"];
+        if is_garbage_week(anchor, pickup) {
+            bins.push("Garbage");
+            if in_season(yard_waste_season(pickup.year())) {
+                bins.push("Yard Waste");
+            }
+        } else {
+            bins.push("Blue");
+        }
+        if in_season(christmas_tree_season(pickup.year())) {
+            bins.push("Christmas Tree");
+        }
+
+        (pickup, bins)
+    }).collect()
+}
+
+#[derive(Properties, PartialEq)]
+pub struct BinSchedulePreviewProps<T: TimeProvider + Clone + Default + PartialEq + 'static = SystemTimeProvider> {
+    /// Where "now" comes from; the preview starts at the next pickup after today
+    #[prop_or_default]
+    pub clock: T,
+    #[prop_or(Weekday::Thu)]
+    pub pickup_weekday: Weekday,
+    /// Known recycling-week (blue bin) collection day, see `Settings::cycle_anchor`
+    pub cycle_anchor: NaiveDate,
+}
+
+#[function_component]
+pub fn BinSchedulePreview<T: TimeProvider + Clone + Default + PartialEq + 'static = SystemTimeProvider>(props: &BinSchedulePreviewProps<T>) -> Html {
+    let weather_ctx = use_context::<WeatherContext>();
+    let anchor = props.cycle_anchor;
+    let today = props.clock.now().date_naive();
+
+    let rows = schedule_preview(today, props.pickup_weekday, anchor).into_iter().map(|(pickup, bins)| {
+        let days_away = (pickup - today).num_days();
+        let day_name = if days_away == 0 { "Today".to_string() } else { pickup.format("%A").to_string() };
+        let icon = weather_ctx.as_ref()
+            .and_then(|ctx| ctx.weather.as_ref())
+            .filter(|_| days_away < FORECAST_DAYS)
+            .and_then(|weather| weather.get_forecast_for_day(&day_name))
+            .map(|forecast| forecast.icon.clone())
+            .unwrap_or_default();
+
+        html! {
+            <tr key={pickup.to_string()}>
+                <td class="text-nowrap">{pickup.format("%a %b %-d").to_string()}</td>
+                <td>{bins.join(", ")}</td>
+                <td class="text-center">{icon}</td>
+            </tr>
+        }
+    }).collect::<Html>();
+
+    html! {
+        <div class="bin-schedule-preview">
+            <a
+                class="small"
+                role="button"
+                data-bs-toggle="collapse"
+                href="#bin-schedule-preview"
+                aria-expanded="false"
+                aria-controls="bin-schedule-preview"
+            >
+                {"See full schedule"}
+            </a>
+            <div class="collapse" id="bin-schedule-preview">
+                <table class="table table-sm mb-0 mt-1">
+                    <thead>
+                        <tr>
+                            <th scope="col">{"Date"}</th>
+                            <th scope="col">{"Bins"}</th>
+                            <th scope="col" class="text-center">{"Weather"}</th>
+                        </tr>
+                    </thead>
+                    <tbody>{rows}</tbody>
+                </table>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::MockTimeProvider;
+
+    fn at(year: i32, month: u32, day: u32, hour: u32) -> MockTimeProvider {
+        MockTimeProvider(Local.with_ymd_and_hms(year, month, day, hour, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn yard_waste_season_runs_march_12_to_december_3() {
+        assert!(!is_yard_waste_season(&at(2025, 12, 12, 9)));
+        assert!(!is_yard_waste_season(&at(2026, 3, 11, 23)));
+        assert!(is_yard_waste_season(&at(2026, 3, 12, 0)));
+        assert!(is_yard_waste_season(&at(2025, 12, 3, 23)));
+    }
+
+    #[test]
+    fn alternate_bin_flips_every_week_from_the_anchor() {
+        // Thursday 2025-10-16 is a blue bin day, pickups on Thursday
+        let anchor = NaiveDate::from_ymd_opt(2025, 10, 16).unwrap();
+        let bin_on = |day: u32| get_alternate_bin(&at(2025, 10, day, 9), anchor, Weekday::Thu);
+
+        assert_eq!(bin_on(16), BinVariation::None);
+        // From the day after, the next pickup is a garbage week
+        assert_eq!(bin_on(17), BinVariation::Yellow);
+        assert_eq!(bin_on(23), BinVariation::Yellow);
+        // 14 days after the anchor is blue again
+        assert_eq!(bin_on(30), BinVariation::None);
+    }
+
+    #[test]
+    fn alternate_bin_agrees_with_the_schedule_for_any_anchor_weekday() {
+        // Zone B's Monday anchor, Thursday pickups
+        let anchor = NaiveDate::from_ymd_opt(2025, 10, 20).unwrap();
+
+        assert_eq!(get_alternate_bin(&at(2025, 10, 23, 9), anchor, Weekday::Thu), BinVariation::None);
+        assert_eq!(get_alternate_bin(&at(2025, 10, 30, 9), anchor, Weekday::Thu), BinVariation::Yellow);
+    }
+
+    #[test]
+    fn hidden_forecast_ignores_the_weather() {
+        let props = |show_weather_forecast| BinComponentProps::<MockTimeProvider> {
+            clock: at(2025, 10, 15, 9),
+            weather: Some(WeatherData::default()),
+            show_weather_forecast,
+            pickup_weekday: Weekday::Thu,
+            temperature_unit: TemperatureUnit::default(),
+            cycle_anchor: NaiveDate::from_ymd_opt(2025, 10, 16).unwrap(),
+        };
+
+        assert!(props(true).shown_weather().is_some());
+        assert_eq!(props(false).shown_weather(), None);
+    }
+
+    #[test]
+    fn schedule_preview_starts_at_the_next_pickup_after_the_clock() {
+        // Blue on 2025-10-16, so garbage and yard waste the week after
+        let anchor = NaiveDate::from_ymd_opt(2025, 10, 16).unwrap();
+        let today = at(2025, 10, 17, 9).now().date_naive();
+        let preview = schedule_preview(today, Weekday::Thu, anchor);
+
+        let dates: Vec<_> = preview.iter().map(|(pickup, _)| pickup.to_string()).collect();
+        assert_eq!(dates, ["2025-10-23", "2025-10-30", "2025-11-06"]);
+        assert_eq!(preview[0].1, ["Green", "Garbage", "Yard Waste"]);
+        assert_eq!(preview[1].1, ["Green", "Blue"]);
+    }
+
+    #[test]
+    fn countdown_is_zero_on_pickup_day() {
+        assert_eq!(days_until(Weekday::Thu, Weekday::Thu), 0);
+        assert_eq!(days_until(Weekday::Fri, Weekday::Thu), 6);
+        assert_eq!(days_until(Weekday::Wed, Weekday::Thu), 1);
+    }
+
+    #[test]
+    fn countdown_lands_on_the_pickup_weekday_for_every_pair() {
+        let week = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun];
+        for today in week {
+            for pickup in week {
+                let days = days_until(today, pickup);
+                assert!(days < 7, "{:?} -> {:?}", today, pickup);
+                assert_eq!((0..days).fold(today, |day, _| day.succ()), pickup);
+            }
+        }
+    }
+
+    #[test]
+    fn status_asks_for_bins_out_the_evening_before() {
+        // 2025-10-15 is a Wednesday
+        assert_eq!(bin_status(at(2025, 10, 15, 19).now(), Weekday::Thu), BinStatus::DaysAway(1));
+        assert_eq!(bin_status(at(2025, 10, 15, 20).now(), Weekday::Thu), BinStatus::PutOutTonight);
+        assert_eq!(bin_status(at(2025, 10, 16, 6).now(), Weekday::Thu), BinStatus::PickupToday);
     }
 }
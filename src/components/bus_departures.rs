@@ -0,0 +1,34 @@
+use yew::{function_component, html, use_context, Html};
+
+use crate::context::bussin::BusContext;
+use crate::context::location::LocationContext;
+
+#[function_component]
+pub fn BusDepartureList() -> Html {
+    let bus_ctx = use_context::<BusContext>().expect("BusContext not found");
+    let location_ctx = use_context::<LocationContext>().expect("LocationContext not found");
+
+    if location_ctx.bus_stop.is_none() {
+        return html! { <div class="text-body-secondary">{"No bus stop saved"}</div> };
+    }
+    if !bus_ctx.is_loaded {
+        return html! { <div class="text-body-secondary">{"Loading departures..."}</div> };
+    }
+    if bus_ctx.data.departures.is_empty() {
+        return html! { <div class="text-body-secondary">{"No upcoming departures"}</div> };
+    }
+
+    html! {
+        <ul class="list-group list-group-flush" aria-label="Upcoming bus departures">
+            {bus_ctx.data.departures.iter().map(|departure| html! {
+                <li class="list-group-item d-flex align-items-center gap-3 fs-5">
+                    <span class="badge bg-danger fs-5">{&departure.route}</span>
+                    <span class="me-auto text-truncate">{&departure.destination}</span>
+                    <span class="fw-bold text-nowrap">
+                        {if departure.minutes == 0 { "Due".to_string() } else { format!("{} min", departure.minutes) }}
+                    </span>
+                </li>
+            }).collect::<Html>()}
+        </ul>
+    }
+}
@@ -1,10 +1,49 @@
-use yew::{function_component, html, Html, Properties};
+use gloo::events::EventListener;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, KeyboardEvent, TouchEvent};
+use yew::{classes, function_component, html, use_context, use_effect_with, use_node_ref, use_state, Callback, ContextProvider, Html, NodeRef, Properties};
+use yew_hooks::use_event;
+
+use crate::utils::use_reduced_motion;
+
+// Horizontal distance a touch has to travel to count as a swipe
+const SWIPE_THRESHOLD_PX: i32 = 50;
+
+// Slides are moved by clicking Bootstrap's own controls so its transitions and
+// `slid.bs.carousel` events keep working
+fn click(button: &NodeRef) {
+    if let Some(button) = button.cast::<HtmlElement>() {
+        button.click();
+    }
+}
+
+// Active slide index, kept in sync with Bootstrap's own slide transitions
+#[derive(Clone, Debug, PartialEq)]
+pub struct CarouselContext {
+    pub active_index: usize,
+    pub item_count: usize,
+    // `#id` of the carousel, for controls that drive it through data attributes
+    pub target: String,
+}
+
+// Provided by each CarouselItem so its content can tell whether it is on screen
+#[derive(Clone, Debug, PartialEq)]
+pub struct CarouselItemContext {
+    pub is_active: bool,
+}
 
 #[derive(Properties, Debug, PartialEq)]
 pub struct CarouselProps {
     pub id: String,
+    /// Number of `CarouselItem`s passed as children. `Html` can't be counted
+    /// directly, so this has to be kept in step with the children by hand;
+    /// debug builds assert the two agree.
+    pub item_count: usize,
     #[prop_or_default]
     pub children: Html,
+    /// Called with the new index after every slide transition
+    #[prop_or_default]
+    pub on_slide_change: Option<Callback<usize>>,
 }
 
 #[function_component]
@@ -12,19 +51,148 @@ pub fn Carousel(props: &CarouselProps) -> Html {
     let id_rand: String = format!("carousel_{}", props.id);
     let id_target = format!("#{}", id_rand);
 
+    if let Html::VList(items) = &props.children {
+        debug_assert_eq!(
+            items.len(),
+            props.item_count,
+            "Carousel item_count doesn't match the number of children"
+        );
+    }
+
+    let node = use_node_ref();
+    let prev_button = use_node_ref();
+    let next_button = use_node_ref();
+    let active_index = use_state(|| 0usize);
+    let touch_start_x = use_state(|| None::<i32>);
+    let reduced_motion = use_reduced_motion();
+
+    {
+        let active_index = active_index.clone();
+        let on_slide_change = props.on_slide_change.clone();
+        // Bootstrap fires this once a slide transition finishes; `to` is the new index
+        use_event(node.clone(), "slid.bs.carousel", move |event: web_sys::Event| {
+            let to = js_sys::Reflect::get(&event, &"to".into())
+                .ok()
+                .and_then(|v| v.as_f64());
+            if let Some(to) = to {
+                active_index.set(to as usize);
+                if let Some(on_slide_change) = &on_slide_change {
+                    on_slide_change.emit(to as usize);
+                }
+            }
+        });
+    }
+
+    // Arrow keys for dashboards driven by a keyboard or TV remote
+    {
+        let prev_button = prev_button.clone();
+        let next_button = next_button.clone();
+        use_effect_with((), move |_| {
+            let listener = EventListener::new(&gloo::utils::window(), "keydown", move |event| {
+                let Some(event) = event.dyn_ref::<KeyboardEvent>() else {
+                    return;
+                };
+                // Leave the arrow keys alone while typing in a form field
+                let in_form_field = event.target()
+                    .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+                    .is_some_and(|el| matches!(el.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT"));
+                if in_form_field {
+                    return;
+                }
+                match event.key().as_str() {
+                    "ArrowLeft" => click(&prev_button),
+                    "ArrowRight" => click(&next_button),
+                    _ => {}
+                }
+            });
+            move || drop(listener)
+        });
+    }
+
+    let ontouchstart = {
+        let touch_start_x = touch_start_x.clone();
+        Callback::from(move |event: TouchEvent| {
+            touch_start_x.set(event.touches().get(0).map(|t| t.client_x()));
+        })
+    };
+
+    let ontouchend = {
+        let touch_start_x = touch_start_x.clone();
+        let prev_button = prev_button.clone();
+        let next_button = next_button.clone();
+        Callback::from(move |event: TouchEvent| {
+            let end_x = event.changed_touches().get(0).map(|t| t.client_x());
+            if let (Some(start_x), Some(end_x)) = (*touch_start_x, end_x) {
+                let delta = end_x - start_x;
+                if delta > SWIPE_THRESHOLD_PX {
+                    click(&prev_button);
+                } else if delta < -SWIPE_THRESHOLD_PX {
+                    click(&next_button);
+                }
+            }
+            touch_start_x.set(None);
+        })
+    };
+
+    let context = CarouselContext {
+        active_index: *active_index,
+        item_count: props.item_count,
+        target: id_target.clone(),
+    };
+
     html! {
-      <div id={id_rand} class="carousel slide h-100">
-        <div class="carousel-inner">
-          {props.children.clone()}
+      <ContextProvider<CarouselContext> {context}>
+        // Swipes are handled here, so Bootstrap's own touch handling is turned off
+        // Without `slide` Bootstrap swaps slides instantly but still fires `slid.bs.carousel`
+        <div
+          id={id_rand}
+          class={classes!("carousel", "h-100", if reduced_motion { "no-transition" } else { "slide" })}
+          ref={node}
+          data-bs-touch="false"
+          {ontouchstart}
+          {ontouchend}
+        >
+          <div class="carousel-inner">
+            {props.children.clone()}
+          </div>
+          <button class="carousel-control-prev" type="button" ref={prev_button} data-bs-target={id_target.clone()} data-bs-slide="prev">
+            // <span class="carousel-control-prev-icon" aria-hidden="true"></span>
+            <span class="visually-hidden">{"Previous"}</span>
+          </button>
+          <button class="carousel-control-next" type="button" ref={next_button} data-bs-target={id_target} data-bs-slide="next">
+            // <span class="carousel-control-next-icon" aria-hidden="true"></span>
+            <span class="visually-hidden">{"Next"}</span>
+          </button>
         </div>
-        <button class="carousel-control-prev" type="button" data-bs-target={id_target.clone()} data-bs-slide="prev">
-          // <span class="carousel-control-prev-icon" aria-hidden="true"></span>
-          <span class="visually-hidden">{"Previous"}</span>
-        </button>
-        <button class="carousel-control-next" type="button" data-bs-target={id_target} data-bs-slide="next">
-          // <span class="carousel-control-next-icon" aria-hidden="true"></span>
-          <span class="visually-hidden">{"Next"}</span>
-        </button>
+        <CarouselIndicators />
+      </ContextProvider<CarouselContext>>
+    }
+}
+
+/// One dot per slide, below the slides rather than over them. Bootstrap's
+/// `data-bs-slide-to` goes straight to the chosen slide in one transition.
+#[function_component]
+pub fn CarouselIndicators() -> Html {
+    let Some(carousel_ctx) = use_context::<CarouselContext>() else {
+        return html! {};
+    };
+
+    let indicators = (0..carousel_ctx.item_count).map(|i| {
+        let is_active = i == carousel_ctx.active_index;
+        html! {
+          <button type="button"
+            data-bs-target={carousel_ctx.target.clone()}
+            data-bs-slide-to={i.to_string()}
+            class={classes!(is_active.then_some("active"))}
+            aria-current={is_active.then_some("true")}
+            aria-label={format!("Slide {}", i + 1)}>
+          </button>
+        }
+    }).collect::<Html>();
+
+    html! {
+      <div class="carousel-indicators position-static mt-2 mb-0">
+        {indicators}
       </div>
     }
 }
@@ -33,22 +201,34 @@ pub fn Carousel(props: &CarouselProps) -> Html {
 pub struct CarouselItemProps {
     #[prop_or_default]
     pub children: Html,
-    #[prop_or_default]
-    pub active: bool,
+    // Position within the parent Carousel, matched against CarouselContext
+    pub index: usize,
 }
 
 #[function_component]
 pub fn CarouselItem(props: &CarouselItemProps) -> Html {
-    let active_class = match props.active {
+    let carousel_ctx = use_context::<CarouselContext>();
+
+    // Outside a Carousel the first item is the one shown
+    let is_active = carousel_ctx.map_or(props.index == 0, |ctx| {
+        debug_assert!(props.index < ctx.item_count, "CarouselItem index out of range");
+        ctx.active_index == props.index
+    });
+
+    let active_class = match is_active {
         true => "active",
         _ => "", // he-he-he-he
     };
 
     let item_class = format!("carousel-item {}", active_class);
 
+    let context = CarouselItemContext { is_active };
+
     html! {
       <div class={item_class}>
+        <ContextProvider<CarouselItemContext> {context}>
           {props.children.clone()}
+        </ContextProvider<CarouselItemContext>>
       </div>
     }
 }
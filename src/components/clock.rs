@@ -1,27 +1,83 @@
 use chrono::Local;
-use yew::{function_component, html, use_state, Html};
-use yew_hooks::use_interval;
+use yew::{function_component, html, use_context, use_state, Callback, Html};
+
+use crate::context::settings::{ClockFormat, SettingsAction, SettingsContext};
+use crate::context::weather::WeatherContext;
+use crate::utils::use_interval_with_visibility;
+
+// Past this the weather line turns orange
+const STALE_WEATHER_SECS: f64 = 2.0 * 60.0 * 60.0;
+
+// Text and class for the line under the clock, from the seconds since the last fetch
+fn weather_age_label(elapsed_secs: Option<f64>) -> (String, &'static str) {
+    match elapsed_secs {
+        Some(elapsed) => {
+            let class = if elapsed > STALE_WEATHER_SECS { "fs-6 text-warning" } else { "fs-6" };
+            (format!("Weather: {} min ago", (elapsed / 60.0).floor() as u64), class)
+        }
+        None => ("Weather: loading...".to_string(), "fs-6"),
+    }
+}
 
 #[function_component]
 pub fn ClockComponent() -> Html {
-    let current_time = use_state(|| Local::now());
+    let current_time = use_state(Local::now);
 
     {
         let state = current_time.clone();
-        use_interval(
-            move || {
+        use_interval_with_visibility(
+            Callback::from(move |_| {
                 state.set(Local::now());
-            },
+            }),
             500,
         );
     }
 
+    // Saved with the rest of the settings
+    let settings = use_context::<SettingsContext>();
+    let clock_format = settings.as_ref()
+        .map(|settings| settings.clock_format)
+        .unwrap_or_default();
+    let onclick = Callback::from(move |_| {
+        if let Some(settings) = settings.as_ref() {
+            settings.dispatch(SettingsAction::SetClockFormat(clock_format.toggled()));
+        }
+    });
+    let toggle_label = match clock_format {
+        ClockFormat::TwentyFourHour => "Switch to 12-hour clock",
+        ClockFormat::TwelveHour => "Switch to 24-hour clock",
+    };
+
+    // Recomputed on every tick along with the time
+    let elapsed = use_context::<WeatherContext>()
+        .and_then(|weather| weather.elapsed_since_fetch_secs());
+    let (weather_text, weather_class) = weather_age_label(elapsed);
+
     html! {
         // FIX: Changed hardcoded 'text-white' to 'text-body' for theme awareness.
-        <div class="fs-1 text-end fw-bold text-body">
-            { format!("{}", current_time.format("%d %b %Y")) }
+        <div class="fs-1 text-end fw-bold text-body" role="button" title={toggle_label} {onclick}>
+            { current_time.format(clock_format.date_format()).to_string() }
             <br/>
-            { format!("{}", current_time.format("%H : %M : %S")) }
+            { current_time.format(clock_format.time_format()).to_string() }
+            <div class={weather_class}>{weather_text}</div>
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weather_age_counts_whole_minutes() {
+        assert_eq!(weather_age_label(Some(59.0)).0, "Weather: 0 min ago");
+        assert_eq!(weather_age_label(Some(5.0 * 60.0 + 30.0)).0, "Weather: 5 min ago");
+        assert_eq!(weather_age_label(None).0, "Weather: loading...");
+    }
+
+    #[test]
+    fn weather_age_turns_orange_after_two_hours() {
+        assert_eq!(weather_age_label(Some(STALE_WEATHER_SECS)).1, "fs-6");
+        assert_eq!(weather_age_label(Some(STALE_WEATHER_SECS + 1.0)).1, "fs-6 text-warning");
+    }
+}
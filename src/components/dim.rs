@@ -1,37 +1,174 @@
 use chrono::{DateTime, Local, Timelike};
-use yew::{function_component, html, use_state, Html};
-use yew_hooks::use_interval;
+use futures_util::StreamExt;
+use std::time::Duration;
+use yew::platform::time::interval;
+use yew::{html, Component, Context, Html, Properties};
 
-const REFRESH_MILLIS: u32 = 900_000; // Every 15 minutes
+use crate::utils::prefers_reduced_motion;
 
-pub fn should_dim() -> bool {
-    let current: DateTime<Local> = Local::now();
+const REFRESH_MINUTES: u64 = 1;
+// How long a tap on the overlay keeps the screen lit
+const WAKE_MINUTES: i64 = 15;
+const FULL_BRIGHTNESS: u8 = 100;
+const TRANSITION: &str = "filter 1s ease-in-out";
 
-    let daylight_hours = 7..20;
+/// Whether `hour` falls in the dim window, which may wrap past midnight.
+/// An empty window (`dim_start == dim_end`) never dims.
+pub fn should_dim(hour: u32, dim_start: u32, dim_end: u32) -> bool {
+    if dim_start <= dim_end {
+        (dim_start..dim_end).contains(&hour)
+    } else {
+        hour >= dim_start || hour < dim_end
+    }
+}
+
+fn scheduled_brightness(props: &DimComponentProps, now: DateTime<Local>) -> u8 {
+    if should_dim(now.hour(), props.dim_start, props.dim_end) {
+        props.dim_brightness.min(FULL_BRIGHTNESS)
+    } else {
+        FULL_BRIGHTNESS
+    }
+}
+
+fn set_body_filter(filter: Option<String>) {
+    let Some(body) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.body())
+    else {
+        return;
+    };
+
+    let style = body.style();
+    let _ = match filter {
+        Some(filter) => style
+            .set_property("transition", if prefers_reduced_motion() { "none" } else { TRANSITION })
+            .and_then(|_| style.set_property("filter", &filter)),
+        None => style
+            .remove_property("filter")
+            .and_then(|_| style.remove_property("transition"))
+            .map(|_| ()),
+    };
+}
+
+#[derive(Properties, PartialEq)]
+pub struct DimComponentProps {
+    /// Hour (0-23) dimming starts
+    #[prop_or(22)]
+    pub dim_start: u32,
+    /// Hour (0-23) full brightness returns
+    #[prop_or(7)]
+    pub dim_end: u32,
+    /// Brightness while dimmed, as a percentage
+    #[prop_or(30)]
+    pub dim_brightness: u8,
+}
+
+pub struct DimComponent {
+    brightness: u8,
+    awake_until: Option<DateTime<Local>>,
+}
 
-    return !daylight_hours.contains(&current.hour());
+pub enum DimComponentMsg {
+    Tick(DateTime<Local>),
+    // Tapping the overlay lights the screen up for a while
+    Wake,
 }
 
-#[function_component]
-pub fn DimComponent() -> Html {
-    let is_dim = use_state(|| should_dim());
+impl Component for DimComponent {
+    type Message = DimComponentMsg;
+    type Properties = DimComponentProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let schedule = interval(Duration::from_secs(60 * REFRESH_MINUTES))
+            .map(|_| DimComponentMsg::Tick(Local::now()));
+        ctx.link().send_stream(schedule);
+
+        Self {
+            brightness: scheduled_brightness(ctx.props(), Local::now()),
+            awake_until: None,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        ctx.link().send_message(DimComponentMsg::Tick(Local::now()));
+        false
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let brightness = match msg {
+            DimComponentMsg::Tick(now) if self.awake_until.is_some_and(|until| now < until) => FULL_BRIGHTNESS,
+            DimComponentMsg::Tick(now) => {
+                self.awake_until = None;
+                scheduled_brightness(ctx.props(), now)
+            }
+            DimComponentMsg::Wake => {
+                self.awake_until = Some(Local::now() + chrono::Duration::minutes(WAKE_MINUTES));
+                FULL_BRIGHTNESS
+            }
+        };
 
-    {
-        let state = is_dim.clone();
-        use_interval(
-            move || {
-                state.set(should_dim());
-            },
-            REFRESH_MILLIS,
-        );
+        if brightness == self.brightness {
+            return false;
+        }
+        self.brightness = brightness;
+        true
     }
 
-    // Altering global state is bad, mmkkkkay?
-    html! {
-        if *is_dim {
-            <style>
-                { "body {opacity: 0.3; background-color: black}" }
-            </style>
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        // Only cover the page while dimmed, otherwise it would swallow every click
+        if self.brightness >= FULL_BRIGHTNESS {
+            return html! {};
+        }
+
+        let onclick = ctx.link().callback(|_| DimComponentMsg::Wake);
+
+        html! {
+            <div
+                class="position-fixed top-0 start-0 w-100 h-100"
+                style="z-index: 1050; background: transparent;"
+                {onclick}
+            />
         }
     }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+        // Altering global state is bad, mmkkkkay? Undone in `destroy`
+        set_body_filter(Some(format!("brightness({}%)", self.brightness)));
+    }
+
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        set_body_filter(None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn dim_window_can_wrap_past_midnight() {
+        assert!(should_dim(22, 22, 7));
+        assert!(should_dim(3, 22, 7));
+        assert!(!should_dim(7, 22, 7));
+        assert!(!should_dim(21, 22, 7));
+    }
+
+    #[test]
+    fn daytime_window_and_empty_window() {
+        assert!(should_dim(13, 12, 14));
+        assert!(!should_dim(14, 12, 14));
+        assert!(!should_dim(11, 12, 14));
+        assert!((0..24).all(|hour| !should_dim(hour, 9, 9)));
+    }
+
+    #[test]
+    fn brightness_follows_the_window_and_caps_at_full() {
+        let props = |dim_brightness| DimComponentProps { dim_start: 22, dim_end: 7, dim_brightness };
+        let at = |hour| Local.with_ymd_and_hms(2025, 10, 16, hour, 0, 0).unwrap();
+
+        assert_eq!(scheduled_brightness(&props(30), at(23)), 30);
+        assert_eq!(scheduled_brightness(&props(30), at(12)), FULL_BRIGHTNESS);
+        assert_eq!(scheduled_brightness(&props(250), at(23)), FULL_BRIGHTNESS);
+    }
 }
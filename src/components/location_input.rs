@@ -1,8 +1,8 @@
-use gloo_storage::{LocalStorage, Storage};
 use web_sys::{wasm_bindgen::JsCast, EventTarget, FormData, HtmlFormElement};
-use yew::{function_component, html, use_context, use_effect_with, Callback, Html, SubmitEvent};
+use yew::{function_component, html, use_context, Callback, Html, SubmitEvent};
 
-use crate::context::location::{Coordinates, LocationContext};
+use crate::context::location::{Coordinates, LocationAction, LocationContext};
+use crate::weather::api::EnvironmentCanadaClient;
 
 #[function_component]
 pub fn LocationInput() -> Html {
@@ -10,15 +10,6 @@ pub fn LocationInput() -> Html {
 
     let location_ctx = use_context::<LocationContext>().unwrap();
 
-    let location_ctx_effect_clone = location_ctx.clone();
-    use_effect_with(location_ctx.coordinates.clone(), move |_| {
-        let current_coordinates_result = LocalStorage::get::<Coordinates>("coordinates");
-
-        if current_coordinates_result.is_ok() {
-            location_ctx_effect_clone.dispatch(current_coordinates_result.unwrap());
-        }
-    });
-
     let location_ctx_submit_clone = location_ctx.clone();
     let form_onsubmit = {
         Callback::from(move |event: SubmitEvent| {
@@ -46,8 +37,8 @@ pub fn LocationInput() -> Html {
                         .unwrap(),
                 };
 
-                LocalStorage::set("coordinates", coordinates.clone()).unwrap();
-                location_ctx_submit_clone.dispatch(coordinates.clone());
+                // LocationProvider persists it
+                location_ctx_submit_clone.dispatch(LocationAction::SetCoordinates(coordinates));
             }
         })
     };
@@ -55,15 +46,60 @@ pub fn LocationInput() -> Html {
     let location_ctx_onclick_clone = location_ctx.clone();
     let clear_onclick = {
         Callback::from(move |_| {
-            LocalStorage::clear();
-
-            location_ctx_onclick_clone.dispatch(Coordinates {
+            // Zeroed coordinates are removed from storage by LocationProvider
+            location_ctx_onclick_clone.dispatch(LocationAction::SetCoordinates(Coordinates {
                 ..Default::default()
-            });
+            }));
+        })
+    };
+
+    let location_ctx_city_clone = location_ctx.clone();
+    let city_onsubmit = {
+        Callback::from(move |event: SubmitEvent| {
+            event.prevent_default();
+
+            let target: Option<EventTarget> = event.target();
+            let form = target.and_then(|t| t.dyn_into::<HtmlFormElement>().ok());
+
+            if let Some(form) = form {
+                let form_data = FormData::new_with_form(&form).unwrap();
+                let province = form_data.get("province").as_string().unwrap_or_default();
+                let city_code = form_data.get("city_code").as_string().unwrap_or_default();
+
+                location_ctx_city_clone.dispatch(LocationAction::SetWeatherCity(
+                    EnvironmentCanadaClient::new(&province, &city_code),
+                ));
+            }
+        })
+    };
+
+    let location_ctx_stop_clone = location_ctx.clone();
+    let stop_onsubmit = {
+        Callback::from(move |event: SubmitEvent| {
+            event.prevent_default();
+
+            let target: Option<EventTarget> = event.target();
+            let form = target.and_then(|t| t.dyn_into::<HtmlFormElement>().ok());
+
+            if let Some(form) = form {
+                let form_data = FormData::new_with_form(&form).unwrap();
+                // An empty stop ID clears the saved one
+                let stop_id = form_data
+                    .get("stop_id")
+                    .as_string()
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty());
+
+                location_ctx_stop_clone.dispatch(match stop_id {
+                    Some(stop_id) => LocationAction::SetBusStop(stop_id),
+                    None => LocationAction::ClearBusStop,
+                });
+            }
         })
     };
 
     let current_coordinates = location_ctx.coordinates.clone();
+    let current_city = location_ctx.weather_city.clone();
 
     html! {
         <div>
@@ -106,6 +142,39 @@ pub fn LocationInput() -> Html {
 
                 <button class="btn btn-primary">{"Save"}</button>
             </form>
+
+            <form class="d-flex flex-column gap-3 mt-4" onsubmit={ city_onsubmit }>
+                <div>{"Weather city: "}{current_city.identifier()}</div>
+
+                <div class="input-group">
+                    <div class="input-group-prepend">
+                        <span class="input-group-text">{"Province"}</span>
+                    </div>
+                    <input type="text" name="province" id="province" class="form-control" placeholder="on" required={true} maxlength="2" />
+                </div>
+
+                <div class="input-group">
+                    <div class="input-group-prepend">
+                        <span class="input-group-text">{"City code"}</span>
+                    </div>
+                    <input type="text" name="city_code" id="city_code" class="form-control" placeholder="143" required={true} />
+                </div>
+
+                <button class="btn btn-primary">{"Set weather city"}</button>
+            </form>
+
+            <form class="d-flex flex-column gap-3 mt-4" onsubmit={ stop_onsubmit }>
+                <div>{"Bus stop: "}{location_ctx.bus_stop.clone().unwrap_or_else(|| "none".to_string())}</div>
+
+                <div class="input-group">
+                    <div class="input-group-prepend">
+                        <span class="input-group-text">{"TTC stop ID"}</span>
+                    </div>
+                    <input type="text" name="stop_id" id="stop_id" class="form-control" placeholder="14260" inputmode="numeric" />
+                </div>
+
+                <button class="btn btn-primary">{"Set bus stop"}</button>
+            </form>
         </div>
     }
 }
@@ -0,0 +1,267 @@
+use chrono::{NaiveDate, Weekday};
+use web_sys::{wasm_bindgen::JsCast, HtmlInputElement, HtmlSelectElement};
+use yew::{function_component, html, use_context, Callback, Event, Html};
+
+use crate::components::bin::ExportIcalButton;
+use crate::context::settings::{CardOrder, ClockFormat, CollectionZone, Settings, SettingsAction, SettingsContext, ThemeOverride};
+use crate::utils::temperature::TemperatureUnit;
+
+// Any darker and the screen can't be read at night at all
+const MIN_DIM_BRIGHTNESS: u8 = 5;
+const TEMPERATURE_UNITS: [TemperatureUnit; 2] = [TemperatureUnit::Celsius, TemperatureUnit::Fahrenheit];
+const REFRESH_INTERVALS_MINS: [u32; 4] = [15, 30, 60, 120];
+
+fn weekdays() -> impl Iterator<Item = Weekday> {
+    std::iter::successors(Some(Weekday::Mon), |day| Some(day.succ())).take(7)
+}
+
+fn selected_index(event: &Event) -> Option<usize> {
+    event.target()
+        .and_then(|t| t.dyn_into::<HtmlSelectElement>().ok())
+        .and_then(|select| usize::try_from(select.selected_index()).ok())
+}
+
+fn input_value(event: &Event) -> Option<String> {
+    event.target()
+        .and_then(|t| t.dyn_into::<HtmlInputElement>().ok())
+        .map(|input| input.value())
+}
+
+// For controls that map straight onto one action
+fn on_select<F>(settings: &SettingsContext, action: F) -> Callback<Event>
+where
+    F: Fn(usize) -> Option<SettingsAction> + 'static,
+{
+    let settings = settings.clone();
+    Callback::from(move |event: Event| {
+        if let Some(action) = selected_index(&event).and_then(&action) {
+            settings.dispatch(action);
+        }
+    })
+}
+
+// Every control edits a copy of the current settings and dispatches it whole
+fn on_change<F>(settings: &SettingsContext, apply: F) -> Callback<Event>
+where
+    F: Fn(&mut Settings, &Event) + 'static,
+{
+    let settings = settings.clone();
+    Callback::from(move |event: Event| {
+        let mut updated = (*settings).clone();
+        apply(&mut updated, &event);
+        if updated != *settings {
+            settings.dispatch(SettingsAction::UpdateSettings(updated));
+        }
+    })
+}
+
+fn hour_input(label: &'static str, value: u32, onchange: Callback<Event>) -> Html {
+    html! {
+        <div class="input-group">
+            <div class="input-group-prepend">
+                <span class="input-group-text">{label}</span>
+            </div>
+            <input type="number" class="form-control" min="0" max="23" value={value.to_string()} {onchange} />
+        </div>
+    }
+}
+
+#[function_component]
+pub fn SettingsPanel() -> Html {
+    let settings = use_context::<SettingsContext>().expect("SettingsContext not found");
+
+    let pickup_day_onchange = on_select(&settings, |i| {
+        weekdays().nth(i).map(SettingsAction::SetBinPickupDay)
+    });
+    // Also drops any corrected anchor
+    let zone_onchange = on_select(&settings, |i| {
+        CollectionZone::ALL.get(i).copied().map(SettingsAction::SetCollectionZone)
+    });
+    let anchor_onchange = on_change(&settings, |settings, event| {
+        let date = input_value(event)
+            .and_then(|d| NaiveDate::parse_from_str(&d, "%Y-%m-%d").ok());
+        if date.is_some() {
+            settings.bin_cycle_anchor = date;
+        }
+    });
+    let anchor_reset = {
+        let settings = settings.clone();
+        Callback::from(move |_| {
+            settings.dispatch(SettingsAction::UpdateSettings(Settings {
+                bin_cycle_anchor: None,
+                ..(*settings).clone()
+            }))
+        })
+    };
+    let clock_onchange = on_change(&settings, |settings, event| {
+        let checked = event.target()
+            .and_then(|t| t.dyn_into::<HtmlInputElement>().ok())
+            .map(|input| input.checked());
+        if let Some(checked) = checked {
+            settings.clock_format = if checked { ClockFormat::TwentyFourHour } else { ClockFormat::TwelveHour };
+        }
+    });
+    let unit_onchange = on_select(&settings, |i| {
+        TEMPERATURE_UNITS.get(i).copied().map(SettingsAction::SetTemperatureUnit)
+    });
+    let theme_onchange = on_select(&settings, |i| {
+        ThemeOverride::ALL.get(i).copied().map(SettingsAction::SetThemeOverride)
+    });
+    let card_order_onchange = on_select(&settings, |i| {
+        CardOrder::ALL.get(i).copied().map(SettingsAction::SetCardOrder)
+    });
+    let refresh_onchange = on_select(&settings, |i| {
+        REFRESH_INTERVALS_MINS.get(i).copied().map(SettingsAction::SetRefreshInterval)
+    });
+    let parse_hour = |event: &Event| input_value(event)
+        .and_then(|v| v.parse::<u32>().ok())
+        .filter(|hour| *hour < 24);
+    let dim_start_onchange = on_change(&settings, move |settings, event| {
+        if let Some(hour) = parse_hour(event) {
+            settings.dim_start_hour = hour;
+        }
+    });
+    let dim_end_onchange = on_change(&settings, move |settings, event| {
+        if let Some(hour) = parse_hour(event) {
+            settings.dim_end_hour = hour;
+        }
+    });
+    let dim_brightness_onchange = on_change(&settings, |settings, event| {
+        if let Some(brightness) = input_value(event).and_then(|v| v.parse::<u8>().ok()) {
+            settings.dim_brightness = brightness.clamp(MIN_DIM_BRIGHTNESS, 100);
+        }
+    });
+    let reset_onclick = {
+        let settings = settings.clone();
+        Callback::from(move |_| settings.dispatch(SettingsAction::ResetToDefaults))
+    };
+
+    html! {
+        <div class="d-flex flex-column gap-3">
+            <h5>{"Bins"}</h5>
+
+            <div class="input-group">
+                <div class="input-group-prepend">
+                    <span class="input-group-text">{"Pickup day"}</span>
+                </div>
+                <select class="form-select" onchange={pickup_day_onchange}>
+                    {weekdays().map(|day| html! {
+                        <option selected={day == settings.bin_pickup_day}>{day.to_string()}</option>
+                    }).collect::<Html>()}
+                </select>
+            </div>
+
+            <div class="input-group">
+                <div class="input-group-prepend">
+                    <span class="input-group-text">{"Collection zone"}</span>
+                </div>
+                <select class="form-select" onchange={zone_onchange}>
+                    {CollectionZone::ALL.iter().map(|zone| html! {
+                        <option selected={*zone == settings.collection_zone}>{zone.label()}</option>
+                    }).collect::<Html>()}
+                </select>
+            </div>
+
+            <div class="input-group">
+                <div class="input-group-prepend">
+                    <span class="input-group-text">{"Any recycling day"}</span>
+                </div>
+                <input
+                    type="date"
+                    class="form-control"
+                    value={settings.cycle_anchor().format("%Y-%m-%d").to_string()}
+                    onchange={anchor_onchange}
+                />
+                if settings.bin_cycle_anchor.is_some() {
+                    <button type="button" class="btn btn-outline-secondary" onclick={anchor_reset}>
+                        {"Use zone default"}
+                    </button>
+                }
+            </div>
+
+            <div>
+                <ExportIcalButton pickup_weekday={settings.bin_pickup_day} cycle_anchor={settings.cycle_anchor()} />
+            </div>
+
+            <h5 class="mt-2">{"Display"}</h5>
+
+            <div class="form-check form-switch">
+                <input
+                    class="form-check-input"
+                    type="checkbox"
+                    id="use_24h_clock"
+                    checked={settings.clock_format == ClockFormat::TwentyFourHour}
+                    onchange={clock_onchange}
+                />
+                <label class="form-check-label" for="use_24h_clock">{"24-hour clock"}</label>
+            </div>
+
+            <div class="input-group">
+                <div class="input-group-prepend">
+                    <span class="input-group-text">{"Temperature"}</span>
+                </div>
+                <select class="form-select" onchange={unit_onchange}>
+                    {TEMPERATURE_UNITS.iter().map(|unit| html! {
+                        <option selected={*unit == settings.temperature_unit}>{unit.symbol()}</option>
+                    }).collect::<Html>()}
+                </select>
+            </div>
+
+            <div class="input-group">
+                <div class="input-group-prepend">
+                    <span class="input-group-text">{"Theme"}</span>
+                </div>
+                <select class="form-select" onchange={theme_onchange}>
+                    {ThemeOverride::ALL.iter().map(|theme| html! {
+                        <option selected={*theme == settings.theme_override}>{theme.label()}</option>
+                    }).collect::<Html>()}
+                </select>
+            </div>
+
+            <div class="input-group">
+                <div class="input-group-prepend">
+                    <span class="input-group-text">{"Weather layout"}</span>
+                </div>
+                <select class="form-select" onchange={card_order_onchange}>
+                    {CardOrder::ALL.iter().map(|order| html! {
+                        <option selected={*order == settings.card_order}>{order.label()}</option>
+                    }).collect::<Html>()}
+                </select>
+            </div>
+
+            <div class="input-group">
+                <div class="input-group-prepend">
+                    <span class="input-group-text">{"Refresh weather every"}</span>
+                </div>
+                <select class="form-select" onchange={refresh_onchange}>
+                    {REFRESH_INTERVALS_MINS.iter().map(|mins| html! {
+                        <option selected={*mins == settings.refresh_interval_mins}>{format!("{} min", mins)}</option>
+                    }).collect::<Html>()}
+                </select>
+            </div>
+
+            {hour_input("Dim from", settings.dim_start_hour, dim_start_onchange)}
+            {hour_input("Dim until", settings.dim_end_hour, dim_end_onchange)}
+
+            <div>
+                <label class="form-label" for="dim_brightness">
+                    {format!("Dimmed brightness: {}%", settings.dim_brightness)}
+                </label>
+                <input
+                    type="range"
+                    class="form-range"
+                    id="dim_brightness"
+                    min={MIN_DIM_BRIGHTNESS.to_string()}
+                    max="100"
+                    step="5"
+                    value={settings.dim_brightness.to_string()}
+                    onchange={dim_brightness_onchange}
+                />
+            </div>
+
+            <button type="button" class="btn btn-outline-danger" onclick={reset_onclick}>
+                {"Reset to defaults"}
+            </button>
+        </div>
+    }
+}
@@ -0,0 +1,169 @@
+// src/components/weather_current.rs
+use chrono::NaiveTime;
+use yew::{function_component, html, use_context, AttrValue, Html, Properties};
+use crate::components::air_quality::AirQualityBadge;
+use crate::components::wind_compass::WindCompass;
+use crate::context::settings::SettingsContext;
+use crate::utils::temperature::{convert, format_temperature};
+use crate::weather::{ComfortLevel, CurrentConditions, HumidexComfortLevel, WeatherTrend, WindChillWarning};
+
+// Only mention "feels like" when it's noticeably different (in °C)
+const FEELS_LIKE_MIN_DIFF: f32 = 2.0;
+const SUN_TIME_FORMAT: &str = "%-I:%M %p";
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct CurrentWeatherCardProps {
+    pub current: CurrentConditions,
+    #[prop_or_default]
+    pub sunrise: Option<NaiveTime>,
+    #[prop_or_default]
+    pub sunset: Option<NaiveTime>,
+    // Computed by the parent so it can refresh without new weather data
+    #[prop_or_default]
+    pub updated_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub trend: Option<WeatherTrend>,
+}
+
+#[function_component(CurrentWeatherCard)]
+pub fn current_weather_card(props: &CurrentWeatherCardProps) -> Html {
+    let current = &props.current;
+    let unit = use_context::<SettingsContext>()
+        .map(|settings| settings.temperature_unit)
+        .unwrap_or_default();
+
+    if !current.has_data() {
+        return html! {
+            <div class="card mb-3 current-weather">
+                <div class="card-body text-muted">
+                    {"No weather data available"}
+                </div>
+            </div>
+        };
+    }
+
+    html! {
+        <div class="card mb-3 current-weather">
+            <div class="card-body">
+                <h5 class="card-title">
+                    {"Current Conditions"}
+                    if !current.station.is_empty() {
+                        <small class="text-muted ms-2">{format!("({})", current.station)}</small>
+                    }
+                </h5>
+                <div class="row">
+                    <div class="col-md-6">
+                        <div class="d-flex align-items-center mb-2">
+                            <span class="weather-icon me-2" style="font-size: 3rem;">{&current.icon}</span>
+                            <div>
+                                <h2 class="mb-0">
+                                    {format!("{:.1}{}", convert(current.temperature, unit), unit.symbol())}
+                                    if let Some(trend) = props.trend {
+                                        <span class="fs-5 ms-2" title={format!("{:?} over the next few hours", trend)}>{trend.arrow()}</span>
+                                    }
+                                </h2>
+                                <p class="mb-0">{&current.condition}</p>
+                                if (current.feels_like() - current.temperature).abs() > FEELS_LIKE_MIN_DIFF {
+                                    <p class="mb-0 text-info small">{format!("Feels like {}", format_temperature(current.feels_like(), unit))}</p>
+                                }
+                                if let Some(level) = current.feels_like_level() {
+                                    <span class={format!("badge rounded-pill {}", comfort_badge_class(level))}>{level.label()}</span>
+                                }
+                            </div>
+                        </div>
+                    </div>
+                    <div class="col-md-6 d-flex align-items-start">
+                        <WindCompass direction={current.wind_direction.clone()} speed={current.wind_speed} />
+                        <div class="small ps-3">
+                            // Wind at top
+                            <div class="mb-2">
+                                {"Wind: "}<strong>{current.wind_description()}</strong>
+                                if let Some(gust) = current.wind_gust {
+                                    <span class="text-warning">{format!(" (gusts {})", gust)}</span>
+                                }
+                            </div>
+
+                            // Row 1: Air Quality
+                            if let Some(ref aq) = current.air_quality {
+                                <div class="mb-2">
+                                    <div class="mb-1">
+                                        <AirQualityBadge current={aq.clone()} />
+                                    </div>
+                                    <div style="max-width: 180px;">
+                                        <div class="position-relative" style="height: 8px; border-radius: 4px; background: linear-gradient(to right, #00e400 0%, #00e400 20%, #ffff00 20%, #ffff00 40%, #ff7e00 40%, #ff7e00 60%, #ff0000 60%, #ff0000 80%, #8f3f97 80%, #8f3f97 100%);">
+                                            <div style={format!("position: absolute; top: -3px; left: calc({}% - 6px); width: 12px; height: 14px; background: white; border: 2px solid #333; border-radius: 3px;", (aq.index / 10.0 * 100.0).min(100.0))}></div>
+                                        </div>
+                                        <div class="d-flex justify-content-between" style="font-size: 0.6rem;">
+                                            <span>{"1"}</span>
+                                            <span>{"3"}</span>
+                                            <span>{"6"}</span>
+                                            <span>{"10+"}</span>
+                                        </div>
+                                    </div>
+                                </div>
+                            }
+
+                            // Row 2: Sunrise | Sunset | Humidity
+                            <div class="d-flex gap-3 mb-1">
+                                if let Some(sunrise) = props.sunrise {
+                                    <span class="text-nowrap">{"🌅 "}<strong>{sunrise.format(SUN_TIME_FORMAT).to_string()}</strong></span>
+                                }
+                                if let Some(sunset) = props.sunset {
+                                    <span class="text-nowrap">{"🌇 "}<strong>{sunset.format(SUN_TIME_FORMAT).to_string()}</strong></span>
+                                }
+                                <span class="text-nowrap">{"💧 "}<strong>{format!("{}%", current.humidity)}</strong></span>
+                                if let Some(uv) = current.uv_index {
+                                    <span class="text-nowrap">{"UV: "}<strong>{uv}</strong></span>
+                                }
+                            </div>
+
+                            // Row 3: Dew Point | Visibility | Pressure (with trend arrow)
+                            <div class="d-flex gap-3 mb-1">
+                                <span class="text-nowrap">{"Dew: "}<strong>{format!("{:.1}{}", convert(current.dewpoint, unit), unit.symbol())}</strong></span>
+                                if let Some(vis) = current.visibility {
+                                    <span class="text-nowrap">{"Vis: "}<strong>{format!("{:.0} km", vis)}</strong></span>
+                                }
+                                <span class="text-nowrap">
+                                    {"Press: "}<strong>{format!("{:.1} kPa", current.pressure)}</strong>
+                                    {get_pressure_arrow(&current.pressure_tendency)}
+                                </span>
+                            </div>
+                        </div>
+                    </div>
+                </div>
+                if let Some(label) = props.updated_label.clone() {
+                    <small class="text-muted d-block text-end">{label}</small>
+                }
+            </div>
+        </div>
+    }
+}
+
+fn comfort_badge_class(level: ComfortLevel) -> &'static str {
+    match level {
+        ComfortLevel::Humidex(HumidexComfortLevel::Comfortable) => "bg-success",
+        ComfortLevel::Humidex(HumidexComfortLevel::SomewhatUncomfortable) => "bg-warning text-dark",
+        ComfortLevel::Humidex(_) => "bg-danger",
+        ComfortLevel::WindChill(WindChillWarning::Low) => "bg-secondary",
+        ComfortLevel::WindChill(WindChillWarning::Moderate) => "bg-info text-dark",
+        ComfortLevel::WindChill(WindChillWarning::High) => "bg-primary",
+        ComfortLevel::WindChill(_) => "bg-danger",
+    }
+}
+
+fn get_pressure_arrow(tendency: &Option<String>) -> Html {
+    if let Some(t) = tendency {
+        let t_lower = t.to_lowercase();
+        if t_lower.contains("rising") || t_lower.contains("up") {
+            html! { <span class="pressure-rising">{" ▲"}</span> }
+        } else if t_lower.contains("falling") || t_lower.contains("down") {
+            html! { <span class="pressure-falling">{" ▼"}</span> }
+        } else if t_lower.contains("steady") || t_lower.contains("stable") {
+            html! { <span class="pressure-steady">{" —"}</span> }
+        } else {
+            html! {}
+        }
+    } else {
+        html! {}
+    }
+}
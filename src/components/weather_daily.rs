@@ -1,6 +1,8 @@
 // src/components/weather_daily.rs
-use yew::{function_component, html, Html, Properties};
-use crate::weather::api::DailyForecast;
+use yew::{function_component, html, use_context, Html, Properties};
+use crate::context::settings::SettingsContext;
+use crate::utils::temperature::{convert, format_temperature, TemperatureUnit};
+use crate::weather::DailyForecast;
 
 // Individual daily card component
 #[allow(dead_code)] // Used by Yew macro
@@ -19,11 +21,15 @@ pub struct DailyComponentProps {
 
 #[function_component]
 pub fn DailyComponent(props: &DailyComponentProps) -> Html {
+    let unit = use_context::<SettingsContext>()
+        .map(|settings| settings.temperature_unit)
+        .unwrap_or_default();
+
     // Format temperature display based on what's available
     let temp_display = match (props.high, props.low) {
-        (Some(h), Some(l)) => format!("{}° / {}°C", h, l),
-        (Some(h), None) => format!("High {}°C", h),
-        (None, Some(l)) => format!("Low {}°C", l),
+        (Some(h), Some(l)) => format!("{:.0}° / {}", convert(h as f32, unit), format_temperature(l as f32, unit)),
+        (Some(h), None) => format!("High {}", format_temperature(h as f32, unit)),
+        (None, Some(l)) => format!("Low {}", format_temperature(l as f32, unit)),
         (None, None) => "N/A".to_string(),
     };
 
@@ -93,23 +99,42 @@ pub fn DailyComponent(props: &DailyComponentProps) -> Html {
     }
 }
 
+// The forecast with its high and low in `unit`, so the tooltip matches the card
+fn in_unit(forecast: &DailyForecast, unit: TemperatureUnit) -> DailyForecast {
+    let to_unit = |degrees: i32| convert(degrees as f32, unit).round() as i32;
+    DailyForecast {
+        high: forecast.high.map(to_unit),
+        low: forecast.low.map(to_unit),
+        ..forecast.clone()
+    }
+}
+
 // Wrapper component that renders all daily forecasts
 #[derive(Clone, PartialEq, Properties)]
 pub struct WeatherDailyProps {
     pub forecasts: Vec<DailyForecast>,
+    /// Cards shown at most, e.g. 3 for a mini-forecast
+    #[prop_or(Some(7))]
+    pub max_days: Option<usize>,
 }
 
 #[function_component(WeatherDaily)]
 pub fn weather_daily(props: &WeatherDailyProps) -> Html {
+    let unit = use_context::<SettingsContext>()
+        .map(|settings| settings.temperature_unit)
+        .unwrap_or_default();
+    let days = props.max_days.unwrap_or(usize::MAX).min(props.forecasts.len());
+    let forecasts = &props.forecasts[..days];
+
     html! {
-        <div class="row g-2 mb-3">
-            <div class="col-12">
-                <h5>{"7-Day Forecast"}</h5>
-            </div>
+        <div class="mb-3">
+            <h5>{format!("{}-Day Forecast", forecasts.len())}</h5>
+            // Cards wrap onto more rows on narrow screens instead of squeezing
+            <div class="d-flex flex-wrap gap-2 justify-content-center">
             {
-                props.forecasts.iter().map(|forecast| {
+                forecasts.iter().map(|forecast| {
                     html! {
-                        <div class="col" key={forecast.day_name.clone()}>
+                        <div style="min-width: 100px; flex: 1 1 100px;" key={forecast.day_name.clone()} title={in_unit(forecast, unit).to_string()}>
                             <DailyComponent
                                 day_name={forecast.day_name.clone()}
                                 icon={forecast.icon.clone()}
@@ -125,6 +150,67 @@ pub fn weather_daily(props: &WeatherDailyProps) -> Html {
                     }
                 }).collect::<Html>()
             }
+            </div>
+            <div class="mt-2">
+                <WeeklySummary forecasts={forecasts.to_vec()} />
+            </div>
+        </div>
+    }
+}
+
+// Bar colour follows the day's high, in °C whatever the display unit
+fn range_colour(high: i32) -> &'static str {
+    if high < 0 {
+        "var(--bs-blue)"
+    } else if high <= 15 {
+        "var(--bs-green)"
+    } else {
+        "var(--bs-orange)"
+    }
+}
+
+/// One bar per day, placed on a shared scale from the week's lowest low to its
+/// highest high, so warm and cold spells stand out at a glance.
+#[function_component(WeeklySummary)]
+pub fn weekly_summary(props: &WeatherDailyProps) -> Html {
+    let unit = use_context::<SettingsContext>()
+        .map(|settings| settings.temperature_unit)
+        .unwrap_or_default();
+
+    let days: Vec<(&DailyForecast, i32, i32)> = props.forecasts.iter()
+        .filter_map(|f| Some((f, f.low?, f.high?)))
+        .collect();
+    let (Some(week_low), Some(week_high)) = (
+        days.iter().map(|(_, low, _)| *low).min(),
+        days.iter().map(|(_, _, high)| *high).max(),
+    ) else {
+        return html! {};
+    };
+    let span = (week_high - week_low).max(1) as f32;
+
+    html! {
+        <div class="d-flex flex-column gap-1" aria-label="Weekly temperature spread">
+            {days.iter().map(|(forecast, low, high)| {
+                let offset = (low - week_low) as f32 / span * 100.0;
+                let width = forecast.temperature_range().unwrap_or(0).max(1) as f32 / span * 100.0;
+                html! {
+                    <div class="d-flex align-items-center gap-2 small text-body" key={forecast.day_name.clone()}>
+                        <span class="text-nowrap" style="width: 6rem;">{&forecast.day_name}</span>
+                        <div class="progress flex-grow-1" style="height: 0.6rem;">
+                            <div class="progress-bar bg-transparent" style={format!("width: {:.1}%;", offset)}></div>
+                            <div
+                                class="progress-bar"
+                                role="progressbar"
+                                style={format!("width: {:.1}%; background-color: {};", width, range_colour(*high))}
+                                aria-label={format!("{} to {}", format_temperature(*low as f32, unit), format_temperature(*high as f32, unit))}
+                            ></div>
+                        </div>
+                        <span class="text-nowrap text-end" style="width: 5rem;">
+                            {format!("{:.0}° / {}", convert(*low as f32, unit), format_temperature(*high as f32, unit))}
+                        </span>
+                    </div>
+                }
+            }).collect::<Html>()}
         </div>
     }
 }
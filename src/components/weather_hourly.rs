@@ -1,44 +1,210 @@
 // src/components/weather_hourly.rs
-use yew::{function_component, html, Html, Properties};
-use crate::weather::api::HourlyForecast;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use gloo::events::EventListener;
+use gloo_timers::callback::Timeout;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+use yew::{function_component, html, use_context, use_effect_with, use_node_ref, use_state, use_state_eq, Html, NodeRef, Properties};
+use crate::context::settings::SettingsContext;
+use crate::context::theme::ThemeContext;
+use crate::utils::get_bootstrap_color;
+use crate::utils::logger::{log_weather, LogLevel};
+use crate::utils::temperature::{convert, TemperatureUnit};
+use crate::weather::{self, HourlyForecast, PrecipitationType};
 use charming::{
-    Chart, HtmlRenderer,
+    Chart, Echarts, WasmRenderer,
+    datatype::CompositeValue,
     component::{Axis, Grid, Legend, Title},
     element::{AxisType, Tooltip, Trigger},
     series::Line,
     theme::Theme,
 };
 
+// Element ECharts draws into; `WasmRenderer` looks it up by id
+const CHART_ID: &str = "weather-chart";
+// Used until the container has been measured
+const DEFAULT_CHART_WIDTH: u32 = 800;
+// Ignore resizes smaller than this, e.g. a scrollbar appearing
+const RESIZE_THRESHOLD_PX: i32 = 20;
+const RESIZE_DEBOUNCE_MS: u32 = 200;
+const WIND_LABEL: &str = "Wind (km/h)";
+
+// Theme colour for a series, with Bootstrap's default if the variable is missing
+fn series_color(property: &str, fallback: &str) -> String {
+    let color = get_bootstrap_color(property);
+    if color.is_empty() { fallback.to_string() } else { color }
+}
+
+// Hours with no precipitation named in the condition keep the plain series
+fn precipitation_label(kind: Option<PrecipitationType>) -> String {
+    format!("{} (%)", kind.map_or("Precipitation", PrecipitationType::label))
+}
+
+fn precipitation_color(kind: Option<PrecipitationType>, is_dark_mode: bool) -> String {
+    match kind {
+        None | Some(PrecipitationType::Rain) => series_color("--bs-primary", "#0d6efd"),
+        Some(PrecipitationType::Sleet) => series_color("--bs-cyan", "#0dcaf0"),
+        Some(PrecipitationType::FreezingRain) => series_color("--bs-orange", "#fd7e14"),
+        // White disappears on the light theme, so snow goes grey there
+        Some(PrecipitationType::Snow) if is_dark_mode => "#ffffff".to_string(),
+        Some(PrecipitationType::Snow) => series_color("--bs-gray-500", "#adb5bd"),
+    }
+}
+
+fn chart_height(width: u32) -> u32 {
+    (width as f64 / 4.6).clamp(120.0, 300.0) as u32
+}
+
+fn container_width(container: &NodeRef) -> Option<i32> {
+    container.cast::<HtmlElement>()
+        .map(|el| el.offset_width())
+        .filter(|&w| w > 0)
+}
+
+// charming has no binding for `dispose`, so it's looked up on the instance.
+// Without it the next `init` on the same element reuses the old instance and theme.
+fn dispose(echarts: &Echarts) {
+    if let Ok(dispose) = js_sys::Reflect::get(echarts, &"dispose".into())
+        .and_then(|f| f.dyn_into::<js_sys::Function>())
+    {
+        let _ = dispose.call0(echarts);
+    }
+}
+
 #[derive(Clone, PartialEq, Properties)]
 pub struct WeatherHourlyProps {
     pub forecasts: Vec<HourlyForecast>,
+    // Rendering inside a hidden carousel slide gives the chart zero size
+    #[prop_or(true)]
+    pub is_visible: bool,
 }
 
 #[function_component(WeatherHourly)]
 pub fn weather_hourly(props: &WeatherHourlyProps) -> Html {
-    // Extract data for the chart
-    let times: Vec<String> = props.forecasts.iter()
-        .map(|f| f.time.clone())
-        .collect();
-    
-    let temperatures: Vec<f64> = props.forecasts.iter()
-        .map(|f| f.temperature as f64)
+    let is_dark_mode = use_context::<ThemeContext>()
+        .map_or(false, |ctx| ctx.is_dark());
+    let unit = use_context::<SettingsContext>()
+        .map(|settings| settings.temperature_unit)
+        .unwrap_or_default();
+
+    // Once the chart has been shown it stays rendered
+    let has_been_visible = use_state(|| props.is_visible);
+    {
+        let has_been_visible = has_been_visible.clone();
+        use_effect_with(props.is_visible, move |is_visible| {
+            if *is_visible && !*has_been_visible {
+                has_been_visible.set(true);
+            }
+        });
+    }
+
+    // Size the chart to its container, re-measuring after the window settles
+    let container = use_node_ref();
+    let chart_width = use_state(|| DEFAULT_CHART_WIDTH);
+    {
+        let container = container.clone();
+        let chart_width = chart_width.clone();
+        use_effect_with(*has_been_visible, move |has_been_visible| {
+            let listener = has_been_visible.then(|| {
+                let last_width = Rc::new(Cell::new(*chart_width as i32));
+                let measure = move || {
+                    if let Some(width) = container_width(&container) {
+                        if (width - last_width.get()).abs() > RESIZE_THRESHOLD_PX {
+                            last_width.set(width);
+                            chart_width.set(width as u32);
+                        }
+                    }
+                };
+                measure();
+
+                let pending = Rc::new(RefCell::new(None::<Timeout>));
+                let measure = Rc::new(measure);
+                EventListener::new(&gloo::utils::window(), "resize", move |_| {
+                    let measure = measure.clone();
+                    // Replacing the timeout cancels the previous one
+                    *pending.borrow_mut() = Some(Timeout::new(RESIZE_DEBOUNCE_MS, move || measure()));
+                })
+            });
+            move || drop(listener)
+        });
+    }
+
+    // Drawn once the container is in the DOM, and again whenever the data,
+    // theme or size changes; the previous instance is disposed first
+    let render_failed = use_state_eq(|| false);
+    {
+        let render_failed = render_failed.clone();
+        use_effect_with(
+            (props.forecasts.clone(), unit, is_dark_mode, *chart_width, *has_been_visible),
+            move |(forecasts, unit, is_dark_mode, width, has_been_visible)| {
+                let echarts = has_been_visible.then(|| {
+                    let theme = if *is_dark_mode { Theme::Dark } else { Theme::Default };
+                    let chart = build_chart(forecasts, *unit, *is_dark_mode);
+                    WasmRenderer::new(*width, chart_height(*width))
+                        .theme(theme)
+                        .render(CHART_ID, &chart)
+                        .map_err(|e| log_weather(&format!("Failed to render chart: {:?}", e), LogLevel::Error))
+                        .ok()
+                }).flatten();
+                render_failed.set(*has_been_visible && echarts.is_none());
+                move || {
+                    if let Some(echarts) = echarts {
+                        dispose(&echarts);
+                    }
+                }
+            },
+        );
+    }
+
+    if !*has_been_visible {
+        return html! {
+            <div class="card mb-3">
+                <div class="card-body" style="min-height: 400px;"></div>
+            </div>
+        };
+    }
+
+    html! {
+        <div class="card mb-3">
+            <div class="card-body">
+                if *render_failed {
+                    <div class="alert alert-warning">{"Failed to render chart"}</div>
+                }
+                <div id={CHART_ID} ref={container}></div>
+            </div>
+        </div>
+    }
+}
+
+// Temperature and precipitation share the left axis; wind gets its own on the right
+fn build_chart(forecasts: &[HourlyForecast], unit: TemperatureUnit, is_dark_mode: bool) -> Chart {
+    // The model, not this component
+    let weather::WeatherHourly { times, temperatures, precipitation, precipitation_types, wind_speeds } =
+        weather::WeatherHourly::from(forecasts);
+    let temperatures: Vec<f64> = temperatures.into_iter()
+        .map(|t| convert(t as f32, unit) as f64)
         .collect();
-    
-    let precipitation: Vec<f64> = props.forecasts.iter()
-        .map(|f| f.pop as f64)
+    let temperature_label = format!("Temperature ({})", unit.symbol());
+    // Missing wind parses as 0, so an all-zero series means there was no data
+    let has_wind = wind_speeds.iter().any(|&w| w > 0.0);
+    // One precipitation series per kind forecast, each blank outside its own hours
+    let precipitation_kinds: Vec<Option<PrecipitationType>> = std::iter::once(None)
+        .chain(PrecipitationType::ALL.into_iter().map(Some))
+        .filter(|kind| precipitation_types.contains(kind))
         .collect();
-
-    // Detect dark mode
-    let is_dark_mode = web_sys::window()
-        .and_then(|w| w.match_media("(prefers-color-scheme: dark)").ok().flatten())
-        .and_then(|mq| Some(mq.matches()))
-        .unwrap_or(false);
+    let mut legend = vec![temperature_label.clone()];
+    legend.extend(precipitation_kinds.iter().map(|&kind| precipitation_label(kind)));
+    if has_wind {
+        legend.push(WIND_LABEL.to_string());
+    }
 
     let text_color = if is_dark_mode { "#ffffff" } else { "#000000" };
+    let temperature_color = series_color("--bs-danger", "#dc3545");
+    let wind_color = series_color("--bs-success", "#198754");
 
-    // Create the chart with single y-axis (temperature)
-    let chart = Chart::new()
+    let mut chart = Chart::new()
         .title(
             Title::new()
                 .text("24-Hour Forecast")
@@ -50,7 +216,7 @@ pub fn weather_hourly(props: &WeatherHourlyProps) -> Html {
         )
         .legend(
             Legend::new()
-                .data(vec!["Temperature (°C)", "Precipitation (%)"])
+                .data(legend)
                 .text_style(charming::element::TextStyle::new().color(text_color))
         )
         .grid(
@@ -69,37 +235,57 @@ pub fn weather_hourly(props: &WeatherHourlyProps) -> Html {
         .y_axis(
             Axis::new()
                 .type_(AxisType::Value)
-                .name("Temperature (°C) / Precipitation (%)")
+                .name(format!("{} / Precipitation (%)", temperature_label))
                 .name_text_style(charming::element::TextStyle::new().color(text_color))
                 .axis_label(charming::element::AxisLabel::new().color(text_color))
         )
         .series(
             Line::new()
-                .name("Temperature (°C)")
+                .name(temperature_label.as_str())
                 .data(temperatures)
                 .smooth(0.3)
-        )
-        .series(
+                .item_style(charming::element::ItemStyle::new().color(temperature_color.as_str()))
+                .line_style(charming::element::LineStyle::new().color(temperature_color.as_str()))
+        );
+
+    for kind in precipitation_kinds {
+        let color = precipitation_color(kind, is_dark_mode);
+        // "-" is a gap in ECharts
+        let data: Vec<CompositeValue> = precipitation.iter()
+            .zip(&precipitation_types)
+            .map(|(&pop, &t)| if t == kind { pop.into() } else { "-".into() })
+            .collect();
+        chart = chart.series(
             Line::new()
-                .name("Precipitation (%)")
-                .data(precipitation)
+                .name(precipitation_label(kind))
+                .data(data)
                 .smooth(0.3)
+                .item_style(charming::element::ItemStyle::new().color(color.as_str()))
+                .line_style(charming::element::LineStyle::new().color(color.as_str()))
         );
+    }
 
-    // Render the chart
-    let theme = if is_dark_mode { Theme::Dark } else { Theme::Default };
-    let renderer = HtmlRenderer::new("weather-chart", 800, 400)
-        .theme(theme);
-    
-    let chart_html = renderer.render(&chart).unwrap_or_else(|_| {
-        "<div class='alert alert-warning'>Failed to render chart</div>".to_string()
-    });
-
-    html! {
-        <div class="card mb-3">
-            <div class="card-body">
-                <div id="weather-chart" dangerously_set_inner_html={chart_html}></div>
-            </div>
-        </div>
+    if has_wind {
+        chart = chart
+            .y_axis(
+                Axis::new()
+                    .type_(AxisType::Value)
+                    .position("right")
+                    .name(WIND_LABEL)
+                    .name_text_style(charming::element::TextStyle::new().color(text_color))
+                    .axis_label(charming::element::AxisLabel::new().color(text_color))
+                    .split_line(charming::element::SplitLine::new().show(false))
+            )
+            .series(
+                Line::new()
+                    .name(WIND_LABEL)
+                    .data(wind_speeds)
+                    .smooth(0.3)
+                    .y_axis_index(1)
+                    .item_style(charming::element::ItemStyle::new().color(wind_color.as_str()))
+                    .line_style(charming::element::LineStyle::new().color(wind_color.as_str()))
+            );
     }
+
+    chart
 }
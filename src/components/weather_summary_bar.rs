@@ -0,0 +1,48 @@
+// src/components/weather_summary_bar.rs
+use yew::{function_component, html, use_context, Html};
+use yew_hooks::use_window_size;
+use crate::context::settings::SettingsContext;
+use crate::context::weather::WeatherContext;
+use crate::utils::temperature::format_temperature;
+
+// Below this width the header row is already full with bins and clock
+const MIN_WIDTH_PX: f64 = 600.0;
+
+#[function_component(WeatherSummaryBar)]
+pub fn weather_summary_bar() -> Html {
+    let weather_ctx = use_context::<WeatherContext>()
+        .expect("WeatherContext not found");
+    let unit = use_context::<SettingsContext>()
+        .map(|settings| settings.temperature_unit)
+        .unwrap_or_default();
+    let (width, _) = use_window_size();
+    let show_summary = width >= MIN_WIDTH_PX;
+
+    if !show_summary {
+        return html! {};
+    }
+
+    let ctx_data = &*weather_ctx;
+    let current = ctx_data.weather.as_ref()
+        .map(|data| data.as_ref())
+        .filter(|current| current.has_data());
+    let summary = if let Some(current) = current {
+        format!(
+            "{} {} | {} | Wind {}",
+            current.icon,
+            format_temperature(current.temperature, unit),
+            current.condition,
+            current.wind_description()
+        )
+    } else if ctx_data.is_loading {
+        "Loading...".to_string()
+    } else {
+        format!("--{}", unit.symbol())
+    };
+
+    html! {
+        <div class="fs-5 text-body text-nowrap align-self-center">
+            { summary }
+        </div>
+    }
+}
@@ -0,0 +1,73 @@
+// src/components/weather_warnings.rs
+use yew::{function_component, html, use_context, Html};
+use crate::context::weather::WeatherContext;
+use crate::weather::WeatherWarning;
+
+const COLLAPSE_ID: &str = "weather-warnings-list";
+
+fn priority_rank(warning: &WeatherWarning) -> u8 {
+    match warning.priority.as_str() {
+        "high" => 0,
+        "medium" => 1,
+        _ => 2,
+    }
+}
+
+fn alert_class(warning: &WeatherWarning) -> &'static str {
+    match warning.priority.as_str() {
+        "high" => "alert-danger",
+        "medium" => "alert-warning",
+        _ => "alert-info",
+    }
+}
+
+#[function_component(WeatherWarnings)]
+pub fn weather_warnings() -> Html {
+    let weather_ctx = use_context::<WeatherContext>()
+        .expect("WeatherContext not found");
+
+    let mut warnings: Vec<&WeatherWarning> = weather_ctx.weather.as_ref()
+        .map(|data| data.warnings.iter().collect())
+        .unwrap_or_default();
+    if warnings.is_empty() {
+        return html! {};
+    }
+    // Most serious first, so the collapsed strip still shows what matters
+    warnings.sort_by_key(|w| priority_rank(w));
+
+    let (first, rest) = warnings.split_first().unwrap();
+
+    html! {
+        <div class="weather-warnings mb-2" aria-live="polite">
+            <div class={format!("alert {} py-2 mb-1 d-flex align-items-center", alert_class(first))} title={first.to_string()}>
+                <strong class="me-auto">{"⚠️ "}{&first.description}</strong>
+                if !first.url.is_empty() {
+                    <a href={first.url.clone()} target="_blank" class="ms-2 small">{"Details →"}</a>
+                }
+                if !rest.is_empty() {
+                    <button
+                        class="btn btn-sm btn-link ms-2"
+                        type="button"
+                        data-bs-toggle="collapse"
+                        data-bs-target={format!("#{}", COLLAPSE_ID)}
+                        aria-expanded="false"
+                        aria-controls={COLLAPSE_ID}
+                    >
+                        {format!("+{} more", rest.len())}
+                    </button>
+                }
+            </div>
+            <div class="collapse" id={COLLAPSE_ID}>
+                {rest.iter().map(|warning| html! {
+                    // Type, priority and a shortened description; the full text is in the tooltip
+                    <div class={format!("alert {} py-2 mb-1", alert_class(warning))} title={warning.description.clone()}>
+                        <strong>{"⚠️ "}{warning.to_string()}</strong>
+                        if !warning.url.is_empty() {
+                            <a href={warning.url.clone()} target="_blank" class="ms-2 small">{"Details →"}</a>
+                        }
+                    </div>
+                }).collect::<Html>()}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,88 @@
+// src/components/wind_compass.rs
+use yew::{function_component, html, AttrValue, Html, Properties};
+
+// 16-point compass, clockwise from north in 22.5° steps
+const COMPASS_POINTS: [&str; 16] = [
+    "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE",
+    "S", "SSW", "SW", "WSW", "W", "WNW", "NW", "NNW",
+];
+const COMPASS_SIZE_PX: u32 = 100;
+
+/// Bearing in degrees for a compass abbreviation such as "WSW". `None` for
+/// anything else, e.g. Environment Canada's "VR" for variable winds.
+pub fn direction_to_degrees(dir: &str) -> Option<f32> {
+    let dir = dir.trim();
+    COMPASS_POINTS.iter()
+        .position(|point| point.eq_ignore_ascii_case(dir))
+        .map(|i| i as f32 * 22.5)
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct WindCompassProps {
+    pub direction: AttrValue,
+    /// km/h
+    pub speed: u32,
+}
+
+#[function_component(WindCompass)]
+pub fn wind_compass(props: &WindCompassProps) -> Html {
+    let bearing = direction_to_degrees(&props.direction);
+    let label = match bearing {
+        Some(_) => format!("Wind {} at {} km/h", props.direction, props.speed),
+        None => format!("Wind {} km/h", props.speed),
+    };
+
+    html! {
+        <svg
+            width={COMPASS_SIZE_PX.to_string()}
+            height={COMPASS_SIZE_PX.to_string()}
+            viewBox="0 0 100 100"
+            role="img"
+            aria-label={label}
+        >
+            <circle cx="50" cy="50" r="46" fill="none" stroke="currentColor" stroke-opacity="0.4" stroke-width="2" />
+            <g fill="currentColor" font-size="10" text-anchor="middle" dominant-baseline="central">
+                <text x="50" y="11">{"N"}</text>
+                <text x="89" y="50">{"E"}</text>
+                <text x="50" y="89">{"S"}</text>
+                <text x="11" y="50">{"W"}</text>
+            </g>
+            if let Some(bearing) = bearing {
+                <polygon
+                    points="50,16 55,50 45,50"
+                    class="text-primary"
+                    fill="currentColor"
+                    transform={format!("rotate({} 50 50)", bearing)}
+                />
+            }
+            <circle cx="50" cy="50" r="14" fill="var(--bs-body-bg)" stroke="currentColor" stroke-opacity="0.4" />
+            <text x="50" y="50" fill="currentColor" font-size="11" font-weight="bold" text-anchor="middle" dominant-baseline="central">
+                {props.speed}
+            </text>
+        </svg>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compass_points_map_clockwise_from_north() {
+        assert_eq!(direction_to_degrees("N"), Some(0.0));
+        assert_eq!(direction_to_degrees("ENE"), Some(67.5));
+        assert_eq!(direction_to_degrees("S"), Some(180.0));
+        assert_eq!(direction_to_degrees("NNW"), Some(337.5));
+    }
+
+    #[test]
+    fn direction_is_trimmed_and_case_insensitive() {
+        assert_eq!(direction_to_degrees(" wsw "), Some(247.5));
+    }
+
+    #[test]
+    fn variable_or_unknown_wind_has_no_bearing() {
+        assert_eq!(direction_to_degrees("VR"), None);
+        assert_eq!(direction_to_degrees(""), None);
+    }
+}
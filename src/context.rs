@@ -1,3 +1,5 @@
 pub mod bussin;
 pub mod location;
+pub mod settings;
+pub mod theme;
 pub mod weather;
@@ -1,45 +1,63 @@
-use chrono::{DateTime, FixedOffset};
 use gloo_console::log;
 use serde_json::Value;
 use std::rc::Rc;
 use yew::{platform::spawn_local, prelude::*};
 
-use crate::utils::fetch;
+use crate::context::location::LocationContext;
+use crate::utils::{fetch, use_interval_with_visibility, FetchError};
 
-#[derive(Debug, PartialEq, Clone)]
+const PREDICTIONS_URL: &str = "https://retro.umoiq.com/service/publicJSONFeed?command=predictionsForStop&a=ttc&stopId=";
+// Predictions drift quickly, so refresh more often than the weather
+const REFRESH_MS: u32 = 60 * 1000;
+const MAX_DEPARTURES: usize = 6;
+
+#[derive(Debug, PartialEq, Clone, Default)]
 pub struct BusCtx {
     pub is_loaded: bool,
     pub data: BusData,
-}
-
-#[allow(dead_code)]
-#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Default)]
-pub struct BusStopsStorage {
-    pub bus_stops: Vec<String>,
+    // Stop the departures belong to
+    pub stop_id: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct BusData {
-    pub departures: Vec<Departure>,
+    pub departures: Vec<BusDeparture>,
 }
 
 #[derive(Debug, Clone, PartialEq, Default)]
-pub struct Departure {
-    pub number: String,
-    pub stop_name: String,
-    pub departure_time: DateTime<FixedOffset>,
-    pub is_cancelled: bool,
+pub struct BusDeparture {
+    pub route: String,
+    pub destination: String,
+    pub minutes: u32,
 }
 
-impl Reducible for BusCtx {
-    type Action = BusData;
+#[derive(Debug, Clone, PartialEq)]
+pub enum BusAction {
+    /// Drops the old stop's departures until the new stop's arrive
+    StopChanged(Option<String>),
+    Loaded { stop_id: String, data: BusData },
+}
 
-    fn reduce(self: Rc<Self>, data: Self::Action) -> Rc<Self> {
-        BusCtx {
-            is_loaded: true,
-            data: data,
+impl Reducible for BusCtx {
+    type Action = BusAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        match action {
+            BusAction::StopChanged(stop_id) if stop_id == self.stop_id => self,
+            BusAction::StopChanged(stop_id) => BusCtx {
+                stop_id,
+                ..BusCtx::default()
+            }
+            .into(),
+            // A late response for a stop that has since changed
+            BusAction::Loaded { stop_id, .. } if self.stop_id.as_ref() != Some(&stop_id) => self,
+            BusAction::Loaded { stop_id, data } => BusCtx {
+                is_loaded: true,
+                data,
+                stop_id: Some(stop_id),
+            }
+            .into(),
         }
-        .into()
     }
 }
 
@@ -53,42 +71,36 @@ pub struct BusProviderProps {
 
 #[function_component]
 pub fn BusProvider(props: &BusProviderProps) -> Html {
-    let data = use_reducer(|| BusCtx {
-        is_loaded: false,
-        data: BusData {
-            ..Default::default()
-        },
-    });
-
-    // let bus_ctx = use_context::<BusContext>().unwrap();
-
-    let _update_every_millis = 1000 * 60 * 60;
-
-    use_effect(move || {
-        // let stops_to_load = LocalStorage::get::<BusStopsStorage>("bus_stops");
-        // if stops_to_load.is_err() {
-        //     log!("Could not load stops from storage");
-        //     return;
-        // }
-
-        // let stops = stops_to_load.unwrap();
-
-        // if stops.bus_stops.len() == 0 {
-        //     return;
-        // }
-
-        // if stops.bus_stops.len() > 5 {
-        //     log!("Probably loading too much stops for now??");
-        //     return;
-        // }
+    let data = use_reducer(BusCtx::default);
+    let location_ctx = use_context::<LocationContext>()
+        .expect("LocationContext not found");
+    let tick = use_state(|| 0u32);
+
+    {
+        let tick = tick.clone();
+        use_interval_with_visibility(
+            Callback::from(move |_| tick.set(tick.wrapping_add(1))),
+            REFRESH_MS,
+        );
+    }
 
-        spawn_local(async move {
-            // let data = fetch_departures("G123123".to_string()).await;
-            // data_clone.dispatcher(BusCtx {
-            //     is_loaded: true,
-            //     data: BusData { departures: data }
-            // })
+    {
+        let data = data.clone();
+        use_effect_with(location_ctx.bus_stop.clone(), move |bus_stop| {
+            data.dispatch(BusAction::StopChanged(bus_stop.clone()));
         });
+    }
+
+    let data_clone = data.clone();
+    use_effect_with((location_ctx.bus_stop.clone(), *tick), move |(bus_stop, _)| {
+        if let Some(stop_id) = bus_stop.clone() {
+            spawn_local(async move {
+                // On failure the last departures stay up until the next tick
+                if let Ok(departures) = fetch_departures(&stop_id).await {
+                    data_clone.dispatch(BusAction::Loaded { stop_id, data: BusData { departures } });
+                }
+            });
+        }
     });
 
     html! {
@@ -98,43 +110,93 @@ pub fn BusProvider(props: &BusProviderProps) -> Html {
     }
 }
 
-// https://transportnsw.info/api/trip/v1/departure-list-request?name=G12312312&type=stop&depArrMacro=dep&depType=stopEvents&excludedModes=2,9,11,1,4,7
-#[allow(dead_code)]
-async fn fetch_departures(stop_number: String) -> Vec<Departure> {
-    let params = [
-        ["name", &stop_number.to_string()],
-        ["depArrMacro", &"dep".to_string()],
-        ["type", &"stop".to_string()],
-        ["depType", &"stopEvents".to_string()],
-        ["excludedModes", &"2,9,11,1,4,7".to_string()], // Only show busses for now
-    ]
-    .map(|x| x.join("="))
-    .join("&");
+// The feed collapses single-element arrays into a bare object
+fn as_list(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(items) => items.iter().collect(),
+        Value::Null => Vec::new(),
+        other => vec![other],
+    }
+}
 
-    let url = "https://transportnsw.info/api/trip/v1/departure-list-request?".to_string() + &params;
+async fn fetch_departures(stop_id: &str) -> Result<Vec<BusDeparture>, FetchError> {
+    let url = format!("{}{}", PREDICTIONS_URL, stop_id);
+    let response = fetch::<Value>(url).await?;
 
-    let response = fetch::<String>(url).await;
-    log!(format!("{:?}", response));
+    let mut departures = Vec::new();
+    for route in as_list(&response["predictions"]) {
+        let route_tag = route["routeTag"].as_str().unwrap_or_default();
+        for direction in as_list(&route["direction"]) {
+            let destination = direction["title"].as_str().unwrap_or_default();
+            for prediction in as_list(&direction["prediction"]) {
+                let Some(minutes) = prediction["minutes"].as_str().and_then(|m| m.parse().ok()) else {
+                    continue;
+                };
+                departures.push(BusDeparture {
+                    route: route_tag.to_string(),
+                    destination: destination.to_string(),
+                    minutes,
+                });
+            }
+        }
+    }
 
-    let data: Value = serde_json::from_str(&response).unwrap();
-    let stop_events = data["stopEvents"].as_array().unwrap();
+    if departures.is_empty() {
+        log!(format!("No departures for stop {}", stop_id));
+    }
 
-    let mut departures = Vec::new();
-    for stop in stop_events {
-        departures.push(Departure {
-            departure_time: DateTime::parse_from_rfc3339(stop["departureTime"].as_str().unwrap())
-                .unwrap(),
-            number: stop["transportation"]["number"]
-                .as_str()
-                .unwrap()
-                .to_string(),
-            stop_name: stop["location"]["disassembledName"]
-                .as_str()
-                .unwrap()
-                .to_string(),
-            is_cancelled: stop["isCancelled"].as_bool().unwrap(),
-        });
+    departures.sort_by_key(|d| d.minutes);
+    departures.truncate(MAX_DEPARTURES);
+    Ok(departures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn departures(route: &str) -> BusData {
+        BusData {
+            departures: vec![BusDeparture { route: route.into(), destination: "Kipling".into(), minutes: 4 }],
+        }
+    }
+
+    fn loaded(stop_id: &str, route: &str) -> Rc<BusCtx> {
+        Rc::new(BusCtx::default())
+            .reduce(BusAction::StopChanged(Some(stop_id.into())))
+            .reduce(BusAction::Loaded { stop_id: stop_id.into(), data: departures(route) })
     }
 
-    return departures;
-}
\ No newline at end of file
+    #[test]
+    fn changing_stop_clears_the_old_departures() {
+        let ctx = loaded("1234", "504").reduce(BusAction::StopChanged(Some("5678".into())));
+
+        assert!(!ctx.is_loaded);
+        assert!(ctx.data.departures.is_empty());
+        assert_eq!(ctx.stop_id.as_deref(), Some("5678"));
+    }
+
+    #[test]
+    fn clearing_the_stop_clears_the_departures() {
+        let ctx = loaded("1234", "504").reduce(BusAction::StopChanged(None));
+
+        assert_eq!(*ctx, BusCtx::default());
+    }
+
+    #[test]
+    fn late_response_for_an_old_stop_is_dropped() {
+        let ctx = loaded("1234", "504")
+            .reduce(BusAction::StopChanged(Some("5678".into())))
+            .reduce(BusAction::Loaded { stop_id: "1234".into(), data: departures("504") });
+
+        assert!(!ctx.is_loaded);
+        assert!(ctx.data.departures.is_empty());
+    }
+
+    #[test]
+    fn same_stop_keeps_its_departures() {
+        let ctx = loaded("1234", "504").reduce(BusAction::StopChanged(Some("1234".into())));
+
+        assert!(ctx.is_loaded);
+        assert_eq!(ctx.data, departures("504"));
+    }
+}
@@ -6,11 +6,29 @@ use serde::Deserialize;
 use yew::{platform::spawn_local, prelude::*};
 
 use super::super::utils::fetch;
+use crate::weather::api::EnvironmentCanadaClient;
+
+const WEATHER_CITY_KEY: &str = "weather_city";
+// Last coordinates the user saved (or that the IP lookup found)
+pub const LOCATION_KEY: &str = "coordinates";
+const BUS_STOP_KEY: &str = "bus_stop";
 
 // Easier to deal with a single 'variable'
 #[derive(Debug, PartialEq, Clone)]
 pub struct LocationCtx {
     pub coordinates: Coordinates,
+    // Environment Canada city page the weather is fetched for
+    pub weather_city: EnvironmentCanadaClient,
+    // TTC stop the bus slide shows departures for
+    pub bus_stop: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum LocationAction {
+    SetCoordinates(Coordinates),
+    SetWeatherCity(EnvironmentCanadaClient),
+    SetBusStop(String),
+    ClearBusStop,
 }
 
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Default)]
@@ -26,14 +44,33 @@ struct GeoLocationApiData {
 }
 
 impl Reducible for LocationCtx {
-    type Action = Coordinates;
-
-    fn reduce(self: Rc<Self>, data: Self::Action) -> Rc<Self> {
-        log!(format!("Reducing: {:?}", data));
-        LocationCtx {
-            coordinates: Coordinates {
-                longitude: data.longitude,
-                latitude: data.latitude,
+    type Action = LocationAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        log!(format!("Reducing: {:?}", action));
+        match action {
+            LocationAction::SetCoordinates(data) => LocationCtx {
+                coordinates: Coordinates {
+                    longitude: data.longitude,
+                    latitude: data.latitude,
+                },
+                weather_city: self.weather_city.clone(),
+                bus_stop: self.bus_stop.clone(),
+            },
+            LocationAction::SetWeatherCity(weather_city) => LocationCtx {
+                coordinates: self.coordinates.clone(),
+                weather_city,
+                bus_stop: self.bus_stop.clone(),
+            },
+            LocationAction::SetBusStop(bus_stop) => LocationCtx {
+                coordinates: self.coordinates.clone(),
+                weather_city: self.weather_city.clone(),
+                bus_stop: Some(bus_stop),
+            },
+            LocationAction::ClearBusStop => LocationCtx {
+                coordinates: self.coordinates.clone(),
+                weather_city: self.weather_city.clone(),
+                bus_stop: None,
             },
         }
         .into()
@@ -51,10 +88,9 @@ pub struct LocationProviderProps {
 #[function_component]
 pub fn LocationProvider(props: &LocationProviderProps) -> Html {
     let location = use_reducer(|| LocationCtx {
-        coordinates: Coordinates {
-            latitude: 0.0,
-            longitude: 0.0,
-        },
+        coordinates: LocalStorage::get::<Coordinates>(LOCATION_KEY).unwrap_or_default(),
+        weather_city: LocalStorage::get::<EnvironmentCanadaClient>(WEATHER_CITY_KEY).unwrap_or_default(),
+        bus_stop: LocalStorage::get::<String>(BUS_STOP_KEY).ok(),
     });
 
     let location_clone = location.clone();
@@ -64,29 +100,43 @@ pub fn LocationProvider(props: &LocationProviderProps) -> Html {
             return;
         }
 
-        let current_coordinates_result = LocalStorage::get::<Coordinates>("coordinates");
+        spawn_local({
+            async move {
+                let url = String::from("https://ipwho.is/");
+                // fetch logs the failure; the user can still enter coordinates
+                let Ok(data) = fetch::<GeoLocationApiData>(url).await else {
+                    return;
+                };
+
+                log!(format!("{:?}", data));
+
+                location_clone.dispatch(LocationAction::SetCoordinates(Coordinates {
+                    latitude: data.latitude,
+                    longitude: data.longitude,
+                }));
+            }
+        });
+    });
 
-        if current_coordinates_result.is_ok() {
-            let data = current_coordinates_result.unwrap();
-            location_clone.dispatch(Coordinates {
-                latitude: data.latitude,
-                longitude: data.longitude,
-            });
+    // Write coordinates back on every change; cleared ones are dropped
+    use_effect_with(location.coordinates.clone(), |coordinates| {
+        if coordinates.latitude == 0.0 {
+            LocalStorage::delete(LOCATION_KEY);
         } else {
-            spawn_local({
-                async move {
-                    let url = String::from("https://ipwho.is/");
-                    let data = fetch::<GeoLocationApiData>(url).await;
-
-                    log!(format!("{:?}", data));
-
-                    location_clone.dispatch(Coordinates {
-                        latitude: data.latitude,
-                        longitude: data.longitude,
-                    });
-                }
-            });
+            let _ = LocalStorage::set(LOCATION_KEY, coordinates);
+        }
+    });
+
+    // Remember the chosen weather city across reloads
+    use_effect_with(location.weather_city.clone(), |weather_city| {
+        let _ = LocalStorage::set(WEATHER_CITY_KEY, weather_city);
+    });
+
+    use_effect_with(location.bus_stop.clone(), |bus_stop| match bus_stop {
+        Some(stop_id) => {
+            let _ = LocalStorage::set(BUS_STOP_KEY, stop_id);
         }
+        None => LocalStorage::delete(BUS_STOP_KEY),
     });
 
     html! {
@@ -0,0 +1,333 @@
+use std::rc::Rc;
+
+use chrono::{NaiveDate, Weekday};
+use gloo_console::log;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+
+use crate::utils::temperature::TemperatureUnit;
+
+const SETTINGS_KEY: &str = "settings";
+// Where the cycle anchor and dim level lived before they moved into `Settings`
+const LEGACY_BIN_CYCLE_ANCHOR_KEY: &str = "bin_cycle_anchor";
+const LEGACY_DIM_BRIGHTNESS_KEY: &str = "dim_brightness";
+
+/// Which block the weather slide renders first
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum CardOrder {
+    #[default]
+    CurrentFirst,
+    ForecastFirst,
+}
+
+impl CardOrder {
+    pub const ALL: [CardOrder; 2] = [Self::CurrentFirst, Self::ForecastFirst];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::CurrentFirst => "Current conditions first",
+            Self::ForecastFirst => "Forecast first",
+        }
+    }
+
+    /// `(top, bottom)` in this order
+    pub fn arrange<T>(self, current: T, forecast: T) -> (T, T) {
+        match self {
+            Self::CurrentFirst => (current, forecast),
+            Self::ForecastFirst => (forecast, current),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum ClockFormat {
+    #[default]
+    TwentyFourHour,
+    TwelveHour,
+}
+
+impl ClockFormat {
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::TwentyFourHour => Self::TwelveHour,
+            Self::TwelveHour => Self::TwentyFourHour,
+        }
+    }
+
+    pub fn time_format(self) -> &'static str {
+        match self {
+            Self::TwentyFourHour => "%H:%M",
+            Self::TwelveHour => "%-I:%M %p",
+        }
+    }
+
+    // The 12-hour time is wider, so its date is abbreviated to keep the width
+    pub fn date_format(self) -> &'static str {
+        match self {
+            Self::TwentyFourHour => "%A %B %-d",
+            Self::TwelveHour => "%a %b %-d",
+        }
+    }
+}
+
+/// Toronto collection zone. Zones run the same two-week cycle from different
+/// starting days.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum CollectionZone {
+    #[default]
+    ZoneA,
+    ZoneB,
+    ZoneC,
+}
+
+impl CollectionZone {
+    pub const ALL: [CollectionZone; 3] = [Self::ZoneA, Self::ZoneB, Self::ZoneC];
+
+    // A recycling-week (blue bin) collection day in each zone - update from the city's calendar
+    pub fn known_anchor_date(self) -> NaiveDate {
+        let (year, month, day) = match self {
+            Self::ZoneA => (2025, 10, 16),
+            Self::ZoneB => (2025, 10, 20),
+            Self::ZoneC => (2025, 10, 22),
+        };
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::ZoneA => "Zone A",
+            Self::ZoneB => "Zone B",
+            Self::ZoneC => "Zone C",
+        }
+    }
+}
+
+// `Auto` follows the OS colour scheme
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum ThemeOverride {
+    #[default]
+    Auto,
+    Light,
+    Dark,
+}
+
+impl ThemeOverride {
+    pub const ALL: [ThemeOverride; 3] = [Self::Auto, Self::Light, Self::Dark];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Auto => "Match system",
+            Self::Light => "Light",
+            Self::Dark => "Dark",
+        }
+    }
+}
+
+// Missing fields fall back to defaults so older saved settings still load
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub card_order: CardOrder,
+    pub temperature_unit: TemperatureUnit,
+    pub clock_format: ClockFormat,
+    /// How often `WeatherProvider` refetches while the page is visible
+    pub refresh_interval_mins: u32,
+    pub theme_override: ThemeOverride,
+    pub bin_pickup_day: Weekday,
+    pub collection_zone: CollectionZone,
+    /// Hours (0-23) the screen dims between, see `DimComponent`
+    pub dim_start_hour: u32,
+    pub dim_end_hour: u32,
+    /// Screen brightness while dimmed, as a percentage
+    pub dim_brightness: u8,
+    /// Corrected recycling-week (blue bin) collection day; `None` uses the zone's own
+    pub bin_cycle_anchor: Option<NaiveDate>,
+}
+
+impl Settings {
+    pub fn cycle_anchor(&self) -> NaiveDate {
+        self.bin_cycle_anchor
+            .unwrap_or_else(|| self.collection_zone.known_anchor_date())
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            card_order: CardOrder::default(),
+            temperature_unit: TemperatureUnit::default(),
+            clock_format: ClockFormat::default(),
+            refresh_interval_mins: 60,
+            theme_override: ThemeOverride::default(),
+            bin_pickup_day: Weekday::Thu,
+            collection_zone: CollectionZone::default(),
+            dim_start_hour: 22,
+            dim_end_hour: 7,
+            dim_brightness: 30,
+            bin_cycle_anchor: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SettingsAction {
+    SetCardOrder(CardOrder),
+    SetTemperatureUnit(TemperatureUnit),
+    SetClockFormat(ClockFormat),
+    SetRefreshInterval(u32),
+    SetThemeOverride(ThemeOverride),
+    SetBinPickupDay(Weekday),
+    SetCollectionZone(CollectionZone),
+    UpdateSettings(Settings),
+    ResetToDefaults,
+}
+
+impl Reducible for Settings {
+    type Action = SettingsAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut settings = (*self).clone();
+
+        match action {
+            SettingsAction::SetCardOrder(order) => settings.card_order = order,
+            SettingsAction::SetTemperatureUnit(unit) => settings.temperature_unit = unit,
+            SettingsAction::SetClockFormat(format) => settings.clock_format = format,
+            SettingsAction::SetRefreshInterval(mins) => settings.refresh_interval_mins = mins,
+            SettingsAction::SetThemeOverride(theme) => settings.theme_override = theme,
+            SettingsAction::SetBinPickupDay(day) => settings.bin_pickup_day = day,
+            SettingsAction::SetCollectionZone(zone) => {
+                // A corrected anchor belongs to the old zone
+                settings.collection_zone = zone;
+                settings.bin_cycle_anchor = None;
+            }
+            SettingsAction::UpdateSettings(updated) => return updated.into(),
+            SettingsAction::ResetToDefaults => return Settings::default().into(),
+        }
+
+        settings.into()
+    }
+}
+
+pub type SettingsContext = UseReducerHandle<Settings>;
+
+#[derive(Properties, Debug, PartialEq)]
+pub struct SettingsProviderProps {
+    #[prop_or_default]
+    pub children: Html,
+}
+
+fn load_settings() -> Settings {
+    let mut settings = LocalStorage::get::<Settings>(SETTINGS_KEY).unwrap_or_default();
+    if let Ok(anchor) = LocalStorage::get::<NaiveDate>(LEGACY_BIN_CYCLE_ANCHOR_KEY) {
+        settings.bin_cycle_anchor.get_or_insert(anchor);
+        LocalStorage::delete(LEGACY_BIN_CYCLE_ANCHOR_KEY);
+    }
+    if let Ok(brightness) = LocalStorage::get::<u8>(LEGACY_DIM_BRIGHTNESS_KEY) {
+        settings.dim_brightness = brightness.min(100);
+        LocalStorage::delete(LEGACY_DIM_BRIGHTNESS_KEY);
+    }
+    settings
+}
+
+#[function_component]
+pub fn SettingsProvider(props: &SettingsProviderProps) -> Html {
+    let settings = use_reducer(load_settings);
+
+    // Write back on every change
+    use_effect_with((*settings).clone(), |settings| {
+        if let Err(e) = LocalStorage::set(SETTINGS_KEY, settings) {
+            log!(format!("Failed to save settings: {:?}", e));
+        }
+    });
+
+    html! {
+        <ContextProvider<SettingsContext> context={settings}>
+            {props.children.clone()}
+        </ContextProvider<SettingsContext>>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reduce(action: SettingsAction) -> Settings {
+        (*Rc::new(Settings::default()).reduce(action)).clone()
+    }
+
+    #[test]
+    fn each_action_sets_its_own_field() {
+        let cases = [
+            (SettingsAction::SetCardOrder(CardOrder::ForecastFirst),
+                Settings { card_order: CardOrder::ForecastFirst, ..Settings::default() }),
+            (SettingsAction::SetTemperatureUnit(TemperatureUnit::Fahrenheit),
+                Settings { temperature_unit: TemperatureUnit::Fahrenheit, ..Settings::default() }),
+            (SettingsAction::SetClockFormat(ClockFormat::TwelveHour),
+                Settings { clock_format: ClockFormat::TwelveHour, ..Settings::default() }),
+            (SettingsAction::SetRefreshInterval(15),
+                Settings { refresh_interval_mins: 15, ..Settings::default() }),
+            (SettingsAction::SetThemeOverride(ThemeOverride::Dark),
+                Settings { theme_override: ThemeOverride::Dark, ..Settings::default() }),
+            (SettingsAction::SetBinPickupDay(Weekday::Mon),
+                Settings { bin_pickup_day: Weekday::Mon, ..Settings::default() }),
+            (SettingsAction::SetCollectionZone(CollectionZone::ZoneB),
+                Settings { collection_zone: CollectionZone::ZoneB, ..Settings::default() }),
+        ];
+
+        for (action, expected) in cases {
+            assert_eq!(reduce(action.clone()), expected, "{:?}", action);
+        }
+    }
+
+    #[test]
+    fn card_order_arranges_current_and_forecast() {
+        assert_eq!(CardOrder::default().arrange("current", "forecast"), ("current", "forecast"));
+        assert_eq!(CardOrder::ForecastFirst.arrange("current", "forecast"), ("forecast", "current"));
+    }
+
+    #[test]
+    fn changing_zone_drops_the_corrected_anchor() {
+        let corrected = Rc::new(Settings {
+            bin_cycle_anchor: NaiveDate::from_ymd_opt(2025, 10, 9),
+            ..Settings::default()
+        });
+        let settings = corrected.reduce(SettingsAction::SetCollectionZone(CollectionZone::ZoneC));
+
+        assert_eq!(settings.bin_cycle_anchor, None);
+        assert_eq!(settings.cycle_anchor(), CollectionZone::ZoneC.known_anchor_date());
+    }
+
+    #[test]
+    fn update_and_reset_replace_everything() {
+        let updated = Settings { dim_start_hour: 21, dim_brightness: 50, ..Settings::default() };
+        assert_eq!(reduce(SettingsAction::UpdateSettings(updated.clone())), updated);
+
+        let reset = Rc::new(updated).reduce(SettingsAction::ResetToDefaults);
+        assert_eq!(*reset, Settings::default());
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let settings = Settings {
+            clock_format: ClockFormat::TwelveHour,
+            bin_pickup_day: Weekday::Tue,
+            bin_cycle_anchor: NaiveDate::from_ymd_opt(2025, 10, 7),
+            ..Settings::default()
+        };
+        let json = serde_json::to_string(&settings).unwrap();
+
+        assert_eq!(serde_json::from_str::<Settings>(&json).unwrap(), settings);
+    }
+
+    #[test]
+    fn older_saved_settings_still_load() {
+        // Missing fields take their defaults; fields since removed are ignored
+        let json = r#"{"clock_format":"TwelveHour","font_size":"Large","notifications_enabled":true}"#;
+
+        assert_eq!(
+            serde_json::from_str::<Settings>(json).unwrap(),
+            Settings { clock_format: ClockFormat::TwelveHour, ..Settings::default() },
+        );
+    }
+}
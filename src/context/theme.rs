@@ -0,0 +1,56 @@
+use yew::prelude::*;
+
+use crate::context::settings::SettingsContext;
+use crate::utils::theme::{Theme, ThemeManager};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThemeContext {
+    pub theme: Theme,
+}
+
+impl ThemeContext {
+    pub fn is_dark(&self) -> bool {
+        self.theme == Theme::Dark
+    }
+}
+
+#[derive(Properties, Debug, PartialEq)]
+pub struct ThemeProviderProps {
+    #[prop_or_default]
+    pub children: Html,
+}
+
+#[function_component]
+pub fn ThemeProvider(props: &ThemeProviderProps) -> Html {
+    let settings = use_context::<SettingsContext>().expect("SettingsContext not found");
+    let manager = use_memo((), |_| ThemeManager::default());
+    let theme = use_state(|| manager.current_theme());
+
+    // Follow the user's override from settings
+    {
+        let manager = manager.clone();
+        let theme = theme.clone();
+        use_effect_with(settings.theme_override, move |mode| {
+            manager.set_mode(*mode);
+            theme.set(manager.current_theme());
+        });
+    }
+
+    // Track OS preference changes while in auto mode
+    {
+        let manager = manager.clone();
+        let theme = theme.clone();
+        use_effect_with((), move |_| {
+            let listener = manager.watch_system(move |new_theme| theme.set(new_theme));
+            move || drop(listener)
+        });
+    }
+
+    let context = ThemeContext { theme: *theme };
+
+    html! {
+        <ContextProvider<ThemeContext> {context}>
+            {props.children.clone()}
+        </ContextProvider<ThemeContext>>
+    }
+}
@@ -1,110 +1,553 @@
-// src/context/weather.rs - COMPLETE REPLACEMENT
+// src/context/weather.rs
 
+use std::cell::Cell;
 use std::rc::Rc;
+use std::time::Duration;
 use yew::prelude::*;
-use gloo_console::log;
+use gloo_storage::{LocalStorage, Storage};
+use gloo_timers::future::TimeoutFuture;
 use serde::{Deserialize, Serialize};
-use yew_hooks::use_interval;
-use crate::weather::api::{WeatherData, fetch_weather_data};
+use crate::context::location::{Coordinates, LocationContext};
+use crate::context::settings::{Settings, SettingsContext};
+use crate::weather::api::EnvironmentCanadaClient;
+use crate::weather::client::WeatherClient;
+use crate::weather::WeatherData;
+use crate::weather::openmeteo::fetch_openmeteo_fallback;
+use crate::weather::test_data::get_mock_weather;
+use crate::utils::logger::{log_weather, LogLevel};
+use crate::utils::storage::{idb_get, idb_put};
+use crate::utils::{backoff_delay_ms, use_interval_with_visibility};
+use wasm_bindgen::JsValue;
+
+const MAX_ATTEMPTS: u32 = 3;
+// Debug builds fall back to mock data this long after a failed fetch
+const DEV_MOCK_DELAY_MS: u32 = 2000;
+const WEATHER_CACHE_KEY: &str = "weather_cache";
+// Environment Canada updates hourly, so cached data younger than this is kept
+const CACHE_FRESH_FOR: Duration = Duration::from_secs(55 * 60);
+
+// Last good fetch, shown straight away on the next page load. Kept in
+// IndexedDB, with localStorage as the fallback when IndexedDB isn't usable.
+#[derive(Serialize, Deserialize)]
+struct CachedWeather {
+    identifier: String,
+    weather: WeatherData,
+    fetched_at: f64,
+}
+
+/// Where the weather currently in context came from
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub enum WeatherSource {
+    #[default]
+    Live,
+    Mock,
+}
 
 #[derive(Clone, PartialEq, Serialize, Deserialize)]
-pub struct WeatherContextData {
+pub struct WeatherCtx {
     pub weather: Option<WeatherData>,
-    pub loading: bool,
+    pub is_loading: bool,
     pub error: Option<String>,
+    /// Milliseconds since epoch (`Date.now()`) of the last successful fetch
+    pub last_fetched_at: Option<f64>,
+    pub source: WeatherSource,
+    /// Showing data that is out of date: cached data waiting on a fetch, or
+    /// the last good data after a refresh failed
+    pub is_stale: bool,
+    // Bumped by every `Refresh`; the provider fetches when it changes
+    pub refresh_count: u32,
 }
 
-impl Default for WeatherContextData {
+impl Default for WeatherCtx {
     fn default() -> Self {
         Self {
             weather: None,
-            loading: true,
+            is_loading: true,
             error: None,
+            last_fetched_at: None,
+            source: WeatherSource::Live,
+            is_stale: false,
+            refresh_count: 0,
         }
     }
 }
 
-#[derive(Clone, PartialEq)]
-pub struct WeatherContext {
-    pub data: Rc<WeatherContextData>,
-    pub refresh: Callback<()>,
+impl WeatherCtx {
+    /// Starts from the cached weather for `client`, if there is any, while
+    /// the first fetch runs in the background
+    fn restored<W: WeatherClient>(client: &W) -> Self {
+        match LocalStorage::get::<CachedWeather>(WEATHER_CACHE_KEY) {
+            Ok(cached) if cached.identifier == client.identifier() => {
+                Self::from_cache(cached.weather, cached.fetched_at, 0)
+            }
+            _ => Self::default(),
+        }
+    }
+
+    fn from_cache(weather: WeatherData, fetched_at: f64, refresh_count: u32) -> Self {
+        Self {
+            is_stale: weather.is_stale(CACHE_FRESH_FOR),
+            weather: Some(weather),
+            is_loading: false,
+            last_fetched_at: Some(fetched_at),
+            refresh_count,
+            ..Self::default()
+        }
+    }
+
+    /// Seconds elapsed since the last successful fetch, if there has been one
+    pub fn elapsed_since_fetch_secs(&self) -> Option<f64> {
+        self.last_fetched_at
+            .map(|fetched_at| (js_sys::Date::now() - fetched_at) / 1000.0)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum WeatherAction {
+    /// Ask the provider to fetch again
+    Refresh,
+    Loading,
+    Loaded(WeatherData),
+    // Weather saved by an earlier session
+    Restored(WeatherData, f64),
+    // Sample data shown instead of a live reading
+    LoadedMock(WeatherData),
+    Error(String),
 }
 
+impl Reducible for WeatherCtx {
+    type Action = WeatherAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        match action {
+            WeatherAction::Refresh => WeatherCtx {
+                is_loading: true,
+                refresh_count: self.refresh_count.wrapping_add(1),
+                ..(*self).clone()
+            },
+            // Keep whatever was showing until the new data arrives
+            WeatherAction::Loading => WeatherCtx {
+                is_loading: true,
+                error: None,
+                ..(*self).clone()
+            },
+            WeatherAction::Loaded(weather) => WeatherCtx {
+                weather: Some(weather),
+                is_loading: false,
+                error: None,
+                last_fetched_at: Some(js_sys::Date::now()),
+                source: WeatherSource::Live,
+                is_stale: false,
+                refresh_count: self.refresh_count,
+            },
+            WeatherAction::Restored(weather, fetched_at) => {
+                WeatherCtx::from_cache(weather, fetched_at, self.refresh_count)
+            }
+            WeatherAction::LoadedMock(weather) => WeatherCtx {
+                weather: Some(weather),
+                is_loading: false,
+                error: None,
+                last_fetched_at: None,
+                source: WeatherSource::Mock,
+                is_stale: false,
+                refresh_count: self.refresh_count,
+            },
+            // Whatever was shown before stays up, flagged as stale
+            WeatherAction::Error(error) => WeatherCtx {
+                is_loading: false,
+                error: Some(error),
+                is_stale: self.weather.is_some(),
+                ..(*self).clone()
+            },
+        }
+        .into()
+    }
+}
+
+pub type WeatherContext = UseReducerHandle<WeatherCtx>;
+
 #[derive(Properties, PartialEq)]
-pub struct WeatherProviderProps {
+pub struct WeatherProviderProps<W: WeatherClient> {
+    pub client: W,
     pub children: Children,
 }
 
 #[function_component(WeatherProvider)]
-pub fn weather_provider(props: &WeatherProviderProps) -> Html {
-    let state = use_state(WeatherContextData::default);
-    
-    // Refresh callback
-    let refresh = {
+pub fn weather_provider<W: WeatherClient + 'static>(props: &WeatherProviderProps<W>) -> Html {
+    let client = props.client.clone();
+    // Only used by the Open-Meteo fallback
+    let coordinates = use_context::<LocationContext>()
+        .map(|location| location.coordinates.clone());
+    let state = {
+        let client = client.clone();
+        use_reducer(move || WeatherCtx::restored(&client))
+    };
+    // Set while a fetch is running so repeated refreshes don't pile up. A Cell
+    // rather than state, so the async task can clear it without a re-render.
+    let in_flight = use_memo((), |_| Cell::new(false));
+    // Bumped on every fetch; results from an older fetch are dropped
+    let generation = use_memo((), |_| Cell::new(0u32));
+
+    // Fetch whenever a Refresh has been dispatched
+    {
+        let state = state.clone();
+        let in_flight = in_flight.clone();
+        let generation = generation.clone();
+        let client = client.clone();
+        use_effect_with(state.refresh_count, move |refresh_count| {
+            if *refresh_count > 0 && !in_flight.get() {
+                let this_fetch = generation.get().wrapping_add(1);
+                generation.set(this_fetch);
+                in_flight.set(true);
+                spawn_fetch(state, in_flight, generation, this_fetch, client, coordinates);
+            }
+        });
+    }
+
+    // Initial load, and again whenever the city changes. Cached data only
+    // counts on the very first load; after that it belongs to the old city.
+    {
         let state = state.clone();
-        Callback::from(move |_| {
-            let state = state.clone();
+        let in_flight = in_flight.clone();
+        let generation = generation.clone();
+        let is_first_load = use_memo((), |_| Cell::new(true));
+        use_effect_with(client, move |client| {
+            if !is_first_load.replace(false) {
+                // Abandon any fetch still running for the previous city
+                generation.set(generation.get().wrapping_add(1));
+                in_flight.set(false);
+                state.dispatch(WeatherAction::Refresh);
+                return;
+            }
+
+            // The localStorage fallback was already restored synchronously
+            let mut cache_is_fresh = state.weather.is_some() && !state.is_stale;
+            let client = client.clone();
             wasm_bindgen_futures::spawn_local(async move {
-                state.set(WeatherContextData {
-                    weather: None,
-                    loading: true,
-                    error: None,
-                });
-                
-                match fetch_weather_with_retry().await {
-                    Ok(weather) => {
-                        state.set(WeatherContextData {
-                            weather: Some(weather),
-                            loading: false,
-                            error: None,
-                        });
-                    }
-                    Err(e) => {
-                        log!(&format!("Error fetching weather: {}", e));
-                        state.set(WeatherContextData {
-                            weather: None,
-                            loading: false,
-                            error: Some(e),
-                        });
-                    }
+                if let Some(cached) = load_weather_from_idb(&client).await {
+                    cache_is_fresh = !cached.weather.is_stale(CACHE_FRESH_FOR);
+                    state.dispatch(WeatherAction::Restored(cached.weather, cached.fetched_at));
+                }
+                if !cache_is_fresh {
+                    state.dispatch(WeatherAction::Refresh);
                 }
             });
-        })
-    };
-
-    // Initial load
-    {
-        let refresh = refresh.clone();
-        use_effect_with((), move |_| {
-            refresh.emit(());
-            || ()
         });
     }
 
-    // Auto-refresh every hour
+    // Auto-refresh on the interval picked in settings, paused while the page is hidden
     {
-        let refresh = refresh.clone();
-        use_interval(
-            move || {
-                refresh.emit(());
-            },
-            3600000, // 1 hour in milliseconds
+        let state = state.clone();
+        let in_flight = in_flight.clone();
+        let refresh_interval_mins = use_context::<SettingsContext>()
+            .map_or(Settings::default().refresh_interval_mins, |settings| settings.refresh_interval_mins);
+        use_interval_with_visibility(
+            Callback::from(move |_| {
+                if !in_flight.get() {
+                    state.dispatch(WeatherAction::Refresh);
+                }
+            }),
+            refresh_interval_mins.max(1) * 60 * 1000,
         );
     }
 
-    let context = WeatherContext {
-        data: Rc::new((*state).clone()),
-        refresh,
+    html! {
+        <ContextProvider<WeatherContext> context={state}>
+            {props.children.clone()}
+        </ContextProvider<WeatherContext>>
+    }
+}
+
+fn spawn_fetch<W: WeatherClient + 'static>(
+    state: WeatherContext,
+    in_flight: Rc<Cell<bool>>,
+    generation: Rc<Cell<u32>>,
+    this_fetch: u32,
+    client: W,
+    coordinates: Option<Coordinates>,
+) {
+    wasm_bindgen_futures::spawn_local(async move {
+        // The city changed (or a newer fetch started) while this one was running
+        let is_current = || generation.get() == this_fetch;
+
+        // No point burning through retries when the device knows it's offline
+        if !is_online() {
+            if is_current() {
+                log_weather("Offline - skipping the weather fetch", LogLevel::Warn);
+                state.dispatch(offline_action(state.weather.is_some()));
+            }
+            in_flight.set(false);
+            return;
+        }
+
+        state.dispatch(WeatherAction::Loading);
+
+        // Debug builds give up after one attempt, falling back to mock data if there's nothing to show
+        let result = if cfg!(debug_assertions) {
+            client.fetch_weather().await
+        } else {
+            fetch_weather_with_retry(&client, backoff_after).await
+        };
+
+        // Environment Canada is out of reach; try Open-Meteo for the saved coordinates
+        let result = match (result, coordinates.filter(|c| c.latitude != 0.0)) {
+            (Err(e), Some(c)) if is_current() => {
+                fetch_openmeteo_fallback(c.latitude as f64, c.longitude as f64)
+                    .await
+                    .map_err(|fallback_err| format!("{} (fallback: {})", e, fallback_err))
+            }
+            (result, _) => result,
+        };
+
+        if !is_current() {
+            return;
+        }
+
+        match result {
+            Ok(weather) => {
+                log_weather("✓ Weather data loaded successfully", LogLevel::Info);
+                // Fallback data shouldn't stop the next load from trying Environment Canada
+                if !weather.is_fallback() {
+                    save_cache(&client, &weather);
+                }
+                state.dispatch(WeatherAction::Loaded(weather));
+            }
+            // Real data, even stale, beats sample data
+            Err(e) if cfg!(debug_assertions) && state.weather.is_none() => {
+                log_weather(&format!("Error fetching weather: {}. Using mock data", e), LogLevel::Warn);
+                TimeoutFuture::new(DEV_MOCK_DELAY_MS).await;
+                if !is_current() {
+                    return;
+                }
+                state.dispatch(WeatherAction::LoadedMock(get_mock_weather()));
+            }
+            Err(e) => {
+                log_weather(&format!("Error fetching weather: {}", e), LogLevel::Error);
+                state.dispatch(WeatherAction::Error(e));
+            }
+        }
+        in_flight.set(false);
+    });
+}
+
+// Cached or restored data stays up, flagged stale; sample data only fills an empty screen
+fn offline_action(has_weather: bool) -> WeatherAction {
+    if has_weather {
+        WeatherAction::Error("Offline".to_string())
+    } else {
+        WeatherAction::LoadedMock(get_mock_weather())
+    }
+}
+
+fn is_online() -> bool {
+    web_sys::window()
+        .map(|w| w.navigator().on_line())
+        .unwrap_or(true)
+}
+
+async fn load_weather_from_idb<W: WeatherClient>(client: &W) -> Option<CachedWeather> {
+    match idb_get::<CachedWeather>(WEATHER_CACHE_KEY).await {
+        Ok(cached) => cached.filter(|cached| cached.identifier == client.identifier()),
+        Err(e) => {
+            log_weather(&format!("IndexedDB unavailable, using localStorage: {:?}", e), LogLevel::Debug);
+            None
+        }
+    }
+}
+
+async fn save_weather_to_idb(cached: &CachedWeather) -> Result<(), JsValue> {
+    idb_put(WEATHER_CACHE_KEY, cached).await?;
+    // An older localStorage copy would otherwise be restored first next time
+    LocalStorage::delete(WEATHER_CACHE_KEY);
+    Ok(())
+}
+
+fn save_cache<W: WeatherClient>(client: &W, weather: &WeatherData) {
+    let cached = CachedWeather {
+        identifier: client.identifier(),
+        weather: weather.clone(),
+        fetched_at: js_sys::Date::now(),
     };
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Err(e) = save_weather_to_idb(&cached).await {
+            log_weather(&format!("IndexedDB save failed, using localStorage: {:?}", e), LogLevel::Debug);
+            if let Err(e) = LocalStorage::set(WEATHER_CACHE_KEY, cached) {
+                log_weather(&format!("Failed to cache weather: {:?}", e), LogLevel::Warn);
+            }
+        }
+    });
+}
+
+// Logs the failed attempt and waits out its backoff
+async fn backoff_after(attempt: u32, error: String) {
+    let delay_ms = backoff_delay_ms(attempt);
+    log_weather(
+        &format!("Attempt {}/{} failed: {}. Retrying in {}ms...",
+        attempt,
+        MAX_ATTEMPTS,
+        error,
+        delay_ms),
+        LogLevel::Warn,
+    );
+    TimeoutFuture::new(delay_ms).await;
+}
+
+/// Up to `MAX_ATTEMPTS` fetches, awaiting `wait_before_retry(attempt, error)`
+/// after each failure but the last. The wait is passed in so the loop itself
+/// doesn't need a browser.
+async fn fetch_weather_with_retry<W, F, Fut>(client: &W, wait_before_retry: F) -> Result<WeatherData, String>
+where
+    W: WeatherClient,
+    F: Fn(u32, String) -> Fut,
+    Fut: std::future::Future<Output = ()>,
+{
+    let mut attempts = 0;
+
+    loop {
+        attempts += 1;
+
+        match client.fetch_weather().await {
+            Ok(data) => return Ok(data),
+            Err(e) if attempts < MAX_ATTEMPTS => wait_before_retry(attempts, e).await,
+            Err(e) => {
+                return Err(format!(
+                    "Failed after {} attempts. {}",
+                    MAX_ATTEMPTS,
+                    e
+                ));
+            }
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct CityWeatherProviderProps {
+    pub children: Children,
+}
+
+/// `WeatherProvider` for the Environment Canada city picked in `LocationContext`
+#[function_component(CityWeatherProvider)]
+pub fn city_weather_provider(props: &CityWeatherProviderProps) -> Html {
+    let client = use_context::<LocationContext>()
+        .map(|location| location.weather_city.clone())
+        .unwrap_or_default();
 
     html! {
-        <ContextProvider<WeatherContext> context={context}>
+        <WeatherProvider<EnvironmentCanadaClient> {client}>
             {props.children.clone()}
-        </ContextProvider<WeatherContext>>
+        </WeatherProvider<EnvironmentCanadaClient>>
     }
 }
 
-async fn fetch_weather_with_retry() -> Result<WeatherData, String> {
-    // Single attempt - api.rs already has built-in fallback proxies
-    fetch_weather_data().await
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use futures::executor::block_on;
+    use crate::weather::test_data::MockWeatherClient;
+
+    fn showing(weather: WeatherData) -> Rc<WeatherCtx> {
+        Rc::new(WeatherCtx {
+            weather: Some(weather),
+            is_loading: true,
+            last_fetched_at: Some(1_000.0),
+            ..WeatherCtx::default()
+        })
+    }
+
+    #[test]
+    fn error_keeps_the_last_good_data() {
+        let weather = WeatherData { location: "Toronto".into(), ..WeatherData::default() };
+        let ctx = showing(weather).reduce(WeatherAction::Error("timed out".into()));
+
+        // The default reading is NaN, so compare by location rather than as a whole
+        assert_eq!(ctx.weather.as_ref().map(|w| w.location.as_str()), Some("Toronto"));
+        assert_eq!(ctx.last_fetched_at, Some(1_000.0));
+        assert_eq!(ctx.error.as_deref(), Some("timed out"));
+        assert!(ctx.is_stale);
+        assert!(!ctx.is_loading);
+    }
+
+    #[test]
+    fn error_without_data_is_not_stale() {
+        let ctx = Rc::new(WeatherCtx::default()).reduce(WeatherAction::Error("offline".into()));
+
+        assert_eq!(ctx.weather, None);
+        assert!(!ctx.is_stale);
+    }
+
+    #[test]
+    fn loading_clears_the_previous_error() {
+        let failed = showing(WeatherData::default()).reduce(WeatherAction::Error("offline".into()));
+        let ctx = failed.reduce(WeatherAction::Loading);
+
+        assert_eq!(ctx.error, None);
+        assert!(ctx.weather.is_some());
+    }
+
+    #[test]
+    fn offline_keeps_cached_data_and_only_fills_an_empty_screen() {
+        let weather = WeatherData { location: "Toronto".into(), ..WeatherData::default() };
+        let ctx = showing(weather).reduce(offline_action(true));
+
+        assert_eq!(ctx.weather.as_ref().map(|w| w.location.as_str()), Some("Toronto"));
+        assert_eq!(ctx.source, WeatherSource::Live);
+        assert!(ctx.is_stale);
+
+        let ctx = Rc::new(WeatherCtx::default()).reduce(offline_action(false));
+        assert_eq!(ctx.source, WeatherSource::Mock);
+    }
+
+    #[test]
+    fn mock_data_is_marked_as_sample_data() {
+        let failed = Rc::new(WeatherCtx::default()).reduce(WeatherAction::Error("offline".into()));
+        let ctx = failed.reduce(WeatherAction::LoadedMock(get_mock_weather()));
+
+        assert_eq!(ctx.source, WeatherSource::Mock);
+        assert!(ctx.weather.as_ref().is_some_and(|w| w.current.has_data()));
+        // Sample data was never fetched, so it mustn't look recent
+        assert_eq!(ctx.last_fetched_at, None);
+        assert_eq!(ctx.error, None);
+        assert!(!ctx.is_loading);
+    }
+
+    fn fetch_recording_waits(client: &MockWeatherClient) -> (Result<WeatherData, String>, Vec<u32>) {
+        let waits = RefCell::new(Vec::new());
+        let result = block_on(fetch_weather_with_retry(client, |attempt, _| {
+            waits.borrow_mut().push(attempt);
+            async {}
+        }));
+        (result, waits.into_inner())
+    }
+
+    #[test]
+    fn retry_succeeds_after_transient_failures() {
+        let client = MockWeatherClient::failing(MAX_ATTEMPTS - 1);
+        let (result, waits) = fetch_recording_waits(&client);
+
+        assert!(result.is_ok());
+        assert_eq!(client.attempts(), MAX_ATTEMPTS);
+        assert_eq!(waits, vec![1, 2]);
+    }
+
+    #[test]
+    fn retry_gives_up_after_max_attempts() {
+        let client = MockWeatherClient::failing(u32::MAX);
+        let (result, waits) = fetch_recording_waits(&client);
+
+        assert_eq!(result.unwrap_err(), "Failed after 3 attempts. mock failure 3");
+        assert_eq!(client.attempts(), MAX_ATTEMPTS);
+        // No wait after the final attempt
+        assert_eq!(waits, vec![1, 2]);
+    }
+
+    #[test]
+    fn first_success_does_not_wait() {
+        let client = MockWeatherClient::default();
+        let (result, waits) = fetch_recording_waits(&client);
+
+        assert!(result.is_ok());
+        assert_eq!(client.attempts(), 1);
+        assert!(waits.is_empty());
+    }
 }
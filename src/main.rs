@@ -1,49 +1,39 @@
 mod components;
+use components::alert_badge::AlertBadge;
+use components::bus_departures::BusDepartureList;
 use components::carousel::Carousel;
 use components::clock::ClockComponent;
 use components::dim::DimComponent;
 use components::location_input::LocationInput;
-use components::{bin::BinComponent, carousel::CarouselItem};
+use components::settings_panel::SettingsPanel;
+use components::weather_summary_bar::WeatherSummaryBar;
+use components::weather_warnings::WeatherWarnings;
+use components::{bin::{BinComponent, BinSchedulePreview}, carousel::CarouselItem};
 mod context;
-use context::{bussin::BusProvider, location::LocationProvider, weather::WeatherProvider};
+use context::{bussin::BusProvider, location::LocationProvider, settings::SettingsProvider, theme::ThemeProvider, weather::CityWeatherProvider};
 mod utils;
 // Environment Canada weather module
 mod weather;
-// Import the Weather component instead of WeatherDisplay
 use components::weather::Weather;
 
-use yew::{function_component, html, use_context, Html, use_effect_with, hook};
-use web_sys::window;
+use yew::{function_component, html, use_context, use_state, Callback, Html};
 
-#[hook]
-fn use_theme_switcher() {
-    use_effect_with((), |_| {
-        let window = window().expect("window not available");
-        let document = window.document().expect("document not available");
-        let body = document.body().expect("body not available");
-
-        // Check system preference
-        if let Ok(Some(mq)) = window.match_media("(prefers-color-scheme: dark)") {
-            if mq.matches() {
-                let _ = body.set_attribute("data-bs-theme", "dark");
-            } else {
-                let _ = body.set_attribute("data-bs-theme", "light");
-            }
-        }
-
-        || {}
-    });
-}
+const BUS_SLIDE_INDEX: usize = 2;
 
 #[function_component]
 pub fn App() -> Html {
-    use_theme_switcher();
-
     html! {
-        // Wrap everything in WeatherProvider so weather data is available throughout
-        <WeatherProvider>
-            <AppContent />
-        </WeatherProvider>
+        // Settings and theme wrap everything; CityWeatherProvider makes the weather available below
+        <SettingsProvider>
+            <ThemeProvider>
+                // Weather follows the city picked in LocationContext
+                <LocationProvider>
+                    <CityWeatherProvider>
+                        <AppContent />
+                    </CityWeatherProvider>
+                </LocationProvider>
+            </ThemeProvider>
+        </SettingsProvider>
     }
 }
 
@@ -52,32 +42,74 @@ fn AppContent() -> Html {
     // Get weather data from context
     let weather_context = use_context::<context::weather::WeatherContext>()
         .expect("WeatherContext not found");
-    
+    let settings = use_context::<context::settings::SettingsContext>()
+        .expect("SettingsContext not found");
+
+    // Bus predictions aren't fetched until the bus slide has been shown once
+    let bus_slide_seen = use_state(|| false);
+    let on_slide_change = {
+        let bus_slide_seen = bus_slide_seen.clone();
+        Callback::from(move |index: usize| {
+            if index == BUS_SLIDE_INDEX && !*bus_slide_seen {
+                bus_slide_seen.set(true);
+            }
+        })
+    };
+
+    let (alerts_count, severe_alerts) = weather_context.weather.as_ref()
+        .map_or((0, false), |w| (w.active_alerts_count(), w.has_severe_warnings()));
+
     html! {
         <div id="app" class="d-flex flex-column justify-content-between p-2" style="overflow: hidden;">
-            <DimComponent/>
+            <DimComponent
+                dim_start={settings.dim_start_hour}
+                dim_end={settings.dim_end_hour}
+                dim_brightness={settings.dim_brightness}
+            />
+            <WeatherWarnings />
             <div class="d-flex justify-content-between">
-                // BinComponent now receives weather data from context
-                <BinComponent weather={weather_context.data.weather.clone()} />
-                <ClockComponent/>
+                // BinComponent gets its weather data from context
+                <BinComponent
+                    weather={weather_context.weather.clone()}
+                    pickup_weekday={settings.bin_pickup_day}
+                    temperature_unit={settings.temperature_unit}
+                    cycle_anchor={settings.cycle_anchor()}
+                />
+                <AlertBadge
+                    count={alerts_count}
+                    severe={severe_alerts}
+                    carousel_target="#carousel_main"
+                    slide_index={0}
+                />
+                <WeatherSummaryBar />
+                <ClockComponent />
             </div>
-            <LocationProvider>
-                <Carousel id="main">
-                    // Weather component handles its own loading
-                    <CarouselItem active={true}>
-                        <Weather />
-                    </CarouselItem>
-                    
-                    <CarouselItem active={false}>
-                        <LocationInput />
-                    </CarouselItem>
-                    
-                    <CarouselItem active={false}>
+            <BinSchedulePreview
+                pickup_weekday={settings.bin_pickup_day}
+                cycle_anchor={settings.cycle_anchor()}
+            />
+            <Carousel id="main" item_count={4} {on_slide_change}>
+                // Weather component handles its own loading
+                <CarouselItem index={0}>
+                    <Weather />
+                </CarouselItem>
+                
+                <CarouselItem index={1}>
+                    <LocationInput />
+                </CarouselItem>
+                
+                <CarouselItem index={BUS_SLIDE_INDEX}>
+                    if *bus_slide_seen {
                         <BusProvider>
+                            <BusDepartureList />
                         </BusProvider>
-                    </CarouselItem>
-                </Carousel>
-            </LocationProvider>
+                    }
+                </CarouselItem>
+
+                <CarouselItem index={3}>
+                    <SettingsPanel />
+                </CarouselItem>
+            </Carousel>
         </div>
     }
 }
@@ -1,28 +1,209 @@
+mod encoding;
+pub mod ical;
+pub mod logger;
+pub mod storage;
+pub mod temperature;
+pub mod theme;
+
+pub use encoding::decompress_if_needed;
+
+use std::fmt;
+
+use chrono::{DateTime, Local};
+use gloo::events::EventListener;
 use gloo_console::log;
 use gloo_net::http::Request;
 use serde::de::DeserializeOwned;
+use yew::{hook, use_effect_with, use_state, Callback};
+use yew_hooks::{use_interval, use_latest};
+
+/// Where date logic gets "now" from, so it can be pinned to a fixed moment.
+pub trait TimeProvider {
+    fn now(&self) -> DateTime<Local>;
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SystemTimeProvider;
+
+impl TimeProvider for SystemTimeProvider {
+    fn now(&self) -> DateTime<Local> {
+        Local::now()
+    }
+}
+
+/// Always reports the moment it was built with.
+#[cfg(test)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MockTimeProvider(pub DateTime<Local>);
+
+#[cfg(test)]
+impl TimeProvider for MockTimeProvider {
+    fn now(&self) -> DateTime<Local> {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FetchError {
+    // The request never got a response (offline, DNS, CORS...)
+    Network(String),
+    // Status code and status text
+    HttpError(u16, String),
+    ParseError(String),
+}
 
-pub async fn fetch<T>(url: String) -> T
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::Network(e) => write!(f, "Fetch failed: {}", e),
+            FetchError::HttpError(status, text) => write!(f, "HTTP error: {} {}", status, text),
+            FetchError::ParseError(e) => write!(f, "JSON parse error: {}", e),
+        }
+    }
+}
+
+pub async fn fetch<T>(url: String) -> Result<T, FetchError>
 where
-    T: DeserializeOwned + Default,
+    T: DeserializeOwned,
 {
-    let resp = Request::get(&url).send().await;
-    match resp {
-        Ok(r) => {
-            if r.ok() {
-                match r.json::<T>().await {
-                    Ok(data) => return data,
-                    Err(e) => {
-                        log!(format!("JSON parse error: {:?}", e));
-                    }
+    let result = match Request::get(&url).send().await {
+        Ok(r) if r.ok() => r.json::<T>().await
+            .map_err(|e| FetchError::ParseError(e.to_string())),
+        Ok(r) => Err(FetchError::HttpError(r.status(), r.status_text())),
+        Err(e) => Err(FetchError::Network(e.to_string())),
+    };
+    if let Err(e) = &result {
+        log!(e.to_string());
+    }
+    result
+}
+
+const BACKOFF_BASE_MS: u32 = 2000;
+const BACKOFF_MAX_MS: u32 = 30_000;
+const BACKOFF_JITTER_MS: f64 = 500.0;
+
+// Delay before retrying after the given (1-based) failed attempt: 2s, 4s, 8s...
+// capped at 30s, plus up to 500ms of jitter so clients don't retry in lockstep
+pub fn backoff_delay_ms(attempt: u32) -> u32 {
+    jittered_backoff_ms(attempt, js_sys::Math::random())
+}
+
+// `backoff_delay_ms` with the random draw (0.0..1.0) passed in
+fn jittered_backoff_ms(attempt: u32, random: f64) -> u32 {
+    let exponential = BACKOFF_BASE_MS
+        .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+        .min(BACKOFF_MAX_MS);
+    let jitter = (random * BACKOFF_JITTER_MS) as u32;
+    exponential + jitter
+}
+
+/// Reads a Bootstrap CSS variable such as `--bs-primary` from the body, where
+/// `data-bs-theme` is applied, so theme overrides are picked up. Empty if unset.
+pub fn get_bootstrap_color(property: &str) -> String {
+    let document = gloo::utils::document();
+    let element = document.body()
+        .map(web_sys::Element::from)
+        .or_else(|| document.document_element());
+
+    element
+        .and_then(|element| gloo::utils::window().get_computed_style(&element).ok().flatten())
+        .and_then(|style| style.get_property_value(property).ok())
+        .map(|value| value.trim().to_string())
+        .unwrap_or_default()
+}
+
+const REDUCED_MOTION_QUERY: &str = "(prefers-reduced-motion: reduce)";
+
+fn reduced_motion_query() -> Option<web_sys::MediaQueryList> {
+    gloo::utils::window().match_media(REDUCED_MOTION_QUERY).ok().flatten()
+}
+
+/// Whether the OS asks for animations to be kept to a minimum, right now.
+/// Components that can't use hooks read this directly.
+pub fn prefers_reduced_motion() -> bool {
+    reduced_motion_query().is_some_and(|mq| mq.matches())
+}
+
+/// `prefers_reduced_motion`, kept up to date when the OS setting changes.
+#[hook]
+pub fn use_reduced_motion() -> bool {
+    let reduced_motion = use_state(prefers_reduced_motion);
+
+    {
+        let reduced_motion = reduced_motion.clone();
+        use_effect_with((), move |_| {
+            let listener = reduced_motion_query().map(|query| {
+                EventListener::new(&query, "change", move |_| {
+                    reduced_motion.set(prefers_reduced_motion());
+                })
+            });
+            move || drop(listener)
+        });
+    }
+
+    *reduced_motion
+}
+
+fn is_page_visible() -> bool {
+    !gloo::utils::document().hidden()
+}
+
+// The interval `use_interval` should run at; 0 cancels it while hidden
+fn visible_interval_ms(is_visible: bool, interval_ms: u32) -> u32 {
+    if is_visible { interval_ms } else { 0 }
+}
+
+/// Like `use_interval`, but paused while the page is hidden. Fires once
+/// immediately when the page becomes visible again.
+#[hook]
+pub fn use_interval_with_visibility(callback: Callback<()>, interval_ms: u32) {
+    let is_visible = use_state(is_page_visible);
+    let latest_callback = use_latest(callback);
+
+    {
+        let is_visible = is_visible.clone();
+        let latest_callback = latest_callback.clone();
+        use_effect_with((), move |_| {
+            let listener = EventListener::new(&gloo::utils::document(), "visibilitychange", move |_| {
+                let visible = is_page_visible();
+                if visible {
+                    latest_callback.current().emit(());
                 }
-            } else {
-                log!(format!("HTTP error: {} {}", r.status(), r.status_text()));
-            }
-        }
-        Err(e) => {
-            log!(format!("Fetch failed: {:?}", e));
-        }
+                is_visible.set(visible);
+            });
+            move || drop(listener)
+        });
+    }
+
+    use_interval(
+        move || latest_callback.current().emit(()),
+        visible_interval_ms(*is_visible, interval_ms),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let delays: Vec<u32> = (1..=6).map(|attempt| jittered_backoff_ms(attempt, 0.0)).collect();
+
+        assert_eq!(delays, [2000, 4000, 8000, 16_000, 30_000, 30_000]);
+        // Huge attempt counts saturate rather than overflow
+        assert_eq!(jittered_backoff_ms(u32::MAX, 0.0), BACKOFF_MAX_MS);
+    }
+
+    #[test]
+    fn backoff_jitter_stays_under_half_a_second() {
+        assert_eq!(jittered_backoff_ms(1, 0.5), 2250);
+        assert!(jittered_backoff_ms(1, 0.999_999) < 2500);
+        assert!(jittered_backoff_ms(10, 0.999_999) < BACKOFF_MAX_MS + 500);
+    }
+
+    #[test]
+    fn interval_only_runs_while_visible() {
+        assert_eq!(visible_interval_ms(true, 60_000), 60_000);
+        assert_eq!(visible_interval_ms(false, 60_000), 0);
     }
-    T::default()
 }
@@ -0,0 +1,56 @@
+// src/utils/encoding.rs
+use std::io::Read;
+
+use flate2::read::GzDecoder;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Browsers decompress gzip transparently, but some CORS proxies pass the raw
+/// bytes through. Inflate those, and treat anything else as plain UTF-8.
+pub fn decompress_if_needed(bytes: &[u8]) -> Result<String, String> {
+    if !bytes.starts_with(&GZIP_MAGIC) {
+        return String::from_utf8(bytes.to_vec())
+            .map_err(|e| format!("Response is not valid UTF-8: {}", e));
+    }
+
+    let mut text = String::new();
+    GzDecoder::new(bytes)
+        .read_to_string(&mut text)
+        .map_err(|e| format!("Failed to decompress gzip response: {}", e))?;
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    fn gzip(text: &str) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(text.as_bytes()).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn gzip_input_is_inflated() {
+        let json = r#"{"features":[{"properties":{"name":{"en":"Montréal"}}}]}"#;
+        assert_eq!(decompress_if_needed(&gzip(json)).unwrap(), json);
+    }
+
+    #[test]
+    fn plain_input_passes_through() {
+        assert_eq!(decompress_if_needed(b"{\"ok\":true}").unwrap(), "{\"ok\":true}");
+        assert_eq!(decompress_if_needed(b"").unwrap(), "");
+        assert!(decompress_if_needed(&[0xff, 0xfe]).is_err());
+    }
+
+    #[test]
+    fn truncated_gzip_is_an_error() {
+        assert!(decompress_if_needed(&GZIP_MAGIC).is_err());
+        let payload = gzip("truncated payload");
+        assert!(decompress_if_needed(&payload[..payload.len() / 2]).is_err());
+    }
+}
@@ -0,0 +1,148 @@
+// src/utils/ical.rs
+//
+// Minimal RFC 5545 calendar of upcoming collection days, for importing into
+// Google or Apple Calendar.
+use chrono::{Datelike, Duration, NaiveDate, Utc, Weekday};
+
+const PRODID: &str = "-//my-bindicator//Bin Collection//EN";
+
+// Monday of the week `date` falls in
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// The one place the two-week cycle is worked out. The anchor's week is a
+/// recycling (blue bin) week and the week after is garbage. Whole weeks are
+/// counted, so the anchor can fall on any weekday, not just the pickup day.
+pub fn is_garbage_week(known_anchor: NaiveDate, pickup: NaiveDate) -> bool {
+    (week_start(pickup) - week_start(known_anchor)).num_weeks().rem_euclid(2) == 1
+}
+
+/// `from` itself if it is collection day, otherwise the next one
+pub fn next_pickup(from: NaiveDate, pickup_weekday: Weekday) -> NaiveDate {
+    let days_ahead = (pickup_weekday.num_days_from_monday() + 7
+        - from.weekday().num_days_from_monday()) % 7;
+    from + Duration::days(days_ahead as i64)
+}
+
+/// All-day events for the next `weeks` collection days, starting with `start`
+/// if it is collection day. `yard_waste_season` gives the season for a year,
+/// so an export running past New Year uses next year's dates.
+pub fn generate_bin_ical(
+    start: NaiveDate,
+    weeks: u32,
+    known_anchor: NaiveDate,
+    pickup_weekday: Weekday,
+    yard_waste_season: impl Fn(i32) -> (NaiveDate, NaiveDate),
+) -> String {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    let first_pickup = next_pickup(start, pickup_weekday);
+
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODID),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+
+    for week in 0..weeks {
+        let pickup = first_pickup + Duration::weeks(week as i64);
+        let (season_start, season_end) = yard_waste_season(pickup.year());
+        let in_yard_season = pickup >= season_start && pickup <= season_end;
+        let summary = match (is_garbage_week(known_anchor, pickup), in_yard_season) {
+            (false, _) => "Blue Bin",
+            (true, false) => "Green + Garbage Bin",
+            (true, true) => "Green + Garbage + Yard Waste Bin",
+        };
+
+        lines.extend([
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:bin-{}@my-bindicator", pickup.format("%Y%m%d")),
+            format!("DTSTAMP:{}", stamp),
+            format!("DTSTART;VALUE=DATE:{}", pickup.format("%Y%m%d")),
+            format!("DTEND;VALUE=DATE:{}", (pickup + Duration::days(1)).format("%Y%m%d")),
+            format!("SUMMARY:{}", summary),
+            "TRANSP:TRANSPARENT".to_string(),
+            "END:VEVENT".to_string(),
+        ]);
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+    // RFC 5545 wants CRLF line endings, including after the last line
+    lines.join("\r\n") + "\r\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn anchor_week_is_recycling_and_the_next_is_garbage() {
+        // Thursday anchor, Thursday pickups
+        let anchor = date(2025, 10, 16);
+
+        assert!(!is_garbage_week(anchor, anchor));
+        assert!(is_garbage_week(anchor, date(2025, 10, 23)));
+        assert!(!is_garbage_week(anchor, date(2025, 10, 30)));
+        // Anchors after the pickup count backwards the same way
+        assert!(is_garbage_week(anchor, date(2025, 10, 9)));
+    }
+
+    #[test]
+    fn anchor_can_fall_on_another_weekday() {
+        // Zone B's Monday anchor with the default Thursday pickup
+        let monday = date(2025, 10, 20);
+
+        assert!(!is_garbage_week(monday, date(2025, 10, 23)));
+        assert!(is_garbage_week(monday, date(2025, 10, 30)));
+        // A Sunday anchor still belongs to the week that started the Monday before
+        assert!(is_garbage_week(date(2025, 10, 26), date(2025, 10, 16)));
+    }
+
+    fn summaries(ical: &str) -> Vec<(&str, &str)> {
+        let starts = ical.lines().filter_map(|line| line.strip_prefix("DTSTART;VALUE=DATE:"));
+        let summaries = ical.lines().filter_map(|line| line.strip_prefix("SUMMARY:"));
+        starts.zip(summaries).collect()
+    }
+
+    #[test]
+    fn export_uses_each_pickups_own_yard_waste_season() {
+        // Season is March 12 to December 3 every year
+        let season = |year| (date(year, 3, 12), date(year, 12, 3));
+        let ical = generate_bin_ical(date(2025, 11, 20), 20, date(2025, 11, 20), Weekday::Thu, season);
+        let events = summaries(&ical);
+
+        assert_eq!(events.len(), 20);
+        assert_eq!(events[0], ("20251120", "Blue Bin"));
+        assert_eq!(events[1], ("20251127", "Green + Garbage + Yard Waste Bin"));
+        // Past December 3, and then into next year but before March 12
+        assert_eq!(events[3], ("20251211", "Green + Garbage Bin"));
+        assert_eq!(events[7], ("20260108", "Green + Garbage Bin"));
+        // The 2026 season, not the 2025 one the export started in
+        assert_eq!(events[17], ("20260319", "Green + Garbage + Yard Waste Bin"));
+    }
+
+    #[test]
+    fn export_is_a_crlf_calendar() {
+        let ical = generate_bin_ical(date(2025, 10, 17), 2, date(2025, 10, 16), Weekday::Thu, |year| {
+            (date(year, 3, 12), date(year, 12, 3))
+        });
+
+        assert!(ical.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ical.ends_with("END:VCALENDAR\r\n"));
+        assert!(!ical.replace("\r\n", "").contains('\n'));
+        assert_eq!(ical.matches("BEGIN:VEVENT").count(), 2);
+    }
+
+    #[test]
+    fn next_pickup_is_today_on_collection_day() {
+        let thursday = date(2025, 10, 16);
+
+        assert_eq!(next_pickup(thursday, Weekday::Thu), thursday);
+        assert_eq!(next_pickup(date(2025, 10, 17), Weekday::Thu), date(2025, 10, 23));
+    }
+}
@@ -0,0 +1,33 @@
+// src/utils/logger.rs
+use gloo_console::{error, log, warn};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+pub fn log_weather(msg: &str, level: LogLevel) {
+    log_tagged("weather", msg, level);
+}
+
+pub fn log_bin(msg: &str, level: LogLevel) {
+    log_tagged("bin", msg, level);
+}
+
+fn log_tagged(tag: &str, msg: &str, level: LogLevel) {
+    // Keep the console quiet in release builds
+    if !cfg!(debug_assertions) && level < LogLevel::Info {
+        return;
+    }
+
+    let line = format!("[{}] {}", tag, msg);
+    match level {
+        LogLevel::Trace | LogLevel::Debug | LogLevel::Info => log!(line),
+        LogLevel::Warn => warn!(line),
+        LogLevel::Error => error!(line),
+    }
+}
@@ -0,0 +1,77 @@
+// src/utils/storage.rs
+//
+// Small async key/value wrapper over IndexedDB for data too big to read
+// synchronously from localStorage on startup. Values are stored as JSON strings.
+use js_sys::Promise;
+use serde::{de::DeserializeOwned, Serialize};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{IdbDatabase, IdbOpenDbRequest, IdbRequest, IdbTransactionMode};
+
+const DB_NAME: &str = "my-bindicator";
+const DB_VERSION: u32 = 1;
+const STORE_NAME: &str = "cache";
+
+// Resolves with the request's result once it succeeds
+async fn request_result(request: &IdbRequest) -> Result<JsValue, JsValue> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        let on_success_request = request.clone();
+        let on_success = Closure::once_into_js(move || {
+            let result = on_success_request.result().unwrap_or(JsValue::UNDEFINED);
+            let _ = resolve.call1(&JsValue::NULL, &result);
+        });
+        let on_error_request = request.clone();
+        let on_error = Closure::once_into_js(move || {
+            let error = on_error_request.error().ok().flatten()
+                .map(JsValue::from)
+                .unwrap_or_else(|| JsValue::from_str("IndexedDB request failed"));
+            let _ = reject.call1(&JsValue::NULL, &error);
+        });
+        request.set_onsuccess(Some(on_success.unchecked_ref()));
+        request.set_onerror(Some(on_error.unchecked_ref()));
+    });
+    JsFuture::from(promise).await
+}
+
+async fn open_db() -> Result<IdbDatabase, JsValue> {
+    // `None` in private browsing on some browsers
+    let factory = gloo::utils::window()
+        .indexed_db()?
+        .ok_or_else(|| JsValue::from_str("IndexedDB unavailable"))?;
+    let request: IdbOpenDbRequest = factory.open_with_u32(DB_NAME, DB_VERSION)?;
+
+    // Runs before success on the first open, or after a version bump
+    let upgrade_request = request.clone();
+    let on_upgrade = Closure::once_into_js(move || {
+        if let Ok(db) = upgrade_request.result().and_then(|db| db.dyn_into::<IdbDatabase>()) {
+            if !db.object_store_names().contains(STORE_NAME) {
+                let _ = db.create_object_store(STORE_NAME);
+            }
+        }
+    });
+    request.set_onupgradeneeded(Some(on_upgrade.unchecked_ref()));
+
+    request_result(&request).await?.dyn_into::<IdbDatabase>()
+}
+
+pub async fn idb_put<T: Serialize>(key: &str, value: &T) -> Result<(), JsValue> {
+    let json = serde_json::to_string(value).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let db = open_db().await?;
+    let store = db
+        .transaction_with_str_and_mode(STORE_NAME, IdbTransactionMode::Readwrite)?
+        .object_store(STORE_NAME)?;
+    request_result(&store.put_with_key(&JsValue::from_str(&json), &JsValue::from_str(key))?).await?;
+    db.close();
+    Ok(())
+}
+
+/// `Ok(None)` when nothing is stored under `key` or it no longer deserializes
+pub async fn idb_get<T: DeserializeOwned>(key: &str) -> Result<Option<T>, JsValue> {
+    let db = open_db().await?;
+    let store = db
+        .transaction_with_str(STORE_NAME)?
+        .object_store(STORE_NAME)?;
+    let value = request_result(&store.get(&JsValue::from_str(key))?).await?;
+    db.close();
+    Ok(value.as_string().and_then(|json| serde_json::from_str(&json).ok()))
+}
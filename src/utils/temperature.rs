@@ -0,0 +1,71 @@
+// src/utils/temperature.rs
+use serde::{Deserialize, Serialize};
+
+/// Display unit for temperatures. Everything is stored in Celsius and only
+/// converted when rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum TemperatureUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+}
+
+impl TemperatureUnit {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "°F",
+        }
+    }
+
+    pub fn toggled(self) -> Self {
+        match self {
+            TemperatureUnit::Celsius => TemperatureUnit::Fahrenheit,
+            TemperatureUnit::Fahrenheit => TemperatureUnit::Celsius,
+        }
+    }
+}
+
+/// Converts a Celsius reading to `unit`
+pub fn convert(value: f32, unit: TemperatureUnit) -> f32 {
+    match unit {
+        TemperatureUnit::Celsius => value,
+        TemperatureUnit::Fahrenheit => value * 9.0 / 5.0 + 32.0,
+    }
+}
+
+/// Whole-degree reading with the unit symbol, e.g. "46°F"
+pub fn format_temperature(celsius: f32, unit: TemperatureUnit) -> String {
+    // Halves round away from zero, and adding 0.0 keeps -0.4 from showing as "-0"
+    let degrees = convert(celsius, unit).round() + 0.0;
+    format!("{}{}", degrees, unit.symbol())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fahrenheit_conversion_hits_the_fixed_points() {
+        assert_eq!(convert(0.0, TemperatureUnit::Fahrenheit), 32.0);
+        assert_eq!(convert(100.0, TemperatureUnit::Fahrenheit), 212.0);
+        assert_eq!(convert(-40.0, TemperatureUnit::Fahrenheit), -40.0);
+        assert_eq!(convert(-12.5, TemperatureUnit::Celsius), -12.5);
+    }
+
+    #[test]
+    fn negative_readings_round_to_whole_degrees() {
+        assert_eq!(format_temperature(-12.5, TemperatureUnit::Celsius), "-13°C");
+        assert_eq!(format_temperature(-12.4, TemperatureUnit::Celsius), "-12°C");
+        assert_eq!(format_temperature(-0.4, TemperatureUnit::Celsius), "0°C");
+        // -20°C is -4°F
+        assert_eq!(format_temperature(-20.0, TemperatureUnit::Fahrenheit), "-4°F");
+    }
+
+    #[test]
+    fn formatted_reading_ends_with_the_unit_symbol() {
+        assert_eq!(format_temperature(7.8, TemperatureUnit::Celsius), "8°C");
+        assert_eq!(format_temperature(7.8, TemperatureUnit::Fahrenheit), "46°F");
+        assert_eq!(TemperatureUnit::Celsius.toggled().symbol(), "°F");
+    }
+}
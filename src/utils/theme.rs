@@ -0,0 +1,100 @@
+// src/utils/theme.rs
+use std::cell::Cell;
+use std::rc::Rc;
+
+use gloo::events::EventListener;
+use web_sys::MediaQueryList;
+
+use crate::context::settings::ThemeOverride;
+
+const DARK_QUERY: &str = "(prefers-color-scheme: dark)";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Theme {
+    #[default]
+    Light,
+    Dark,
+}
+
+impl Theme {
+    fn as_attr(self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+        }
+    }
+}
+
+/// Owns the `data-bs-theme` attribute on `<body>`. Cheap to clone; clones
+/// share the same mode.
+#[derive(Clone, Default)]
+pub struct ThemeManager {
+    mode: Rc<Cell<ThemeOverride>>,
+}
+
+impl ThemeManager {
+    pub fn set_light(&self) {
+        self.mode.set(ThemeOverride::Light);
+        apply(Theme::Light);
+    }
+
+    pub fn set_dark(&self) {
+        self.mode.set(ThemeOverride::Dark);
+        apply(Theme::Dark);
+    }
+
+    pub fn set_auto(&self) {
+        self.mode.set(ThemeOverride::Auto);
+        apply(system_theme());
+    }
+
+    pub fn set_mode(&self, mode: ThemeOverride) {
+        match mode {
+            ThemeOverride::Auto => self.set_auto(),
+            ThemeOverride::Light => self.set_light(),
+            ThemeOverride::Dark => self.set_dark(),
+        }
+    }
+
+    pub fn current_theme(&self) -> Theme {
+        match self.mode.get() {
+            ThemeOverride::Auto => system_theme(),
+            ThemeOverride::Light => Theme::Light,
+            ThemeOverride::Dark => Theme::Dark,
+        }
+    }
+
+    /// Re-applies the theme whenever the OS preference flips while in auto
+    /// mode. The listener is removed when the returned value is dropped.
+    pub fn watch_system(&self, on_change: impl Fn(Theme) + 'static) -> Option<EventListener> {
+        let query = dark_query()?;
+        let manager = self.clone();
+        Some(EventListener::new(&query, "change", move |_| {
+            if manager.mode.get() == ThemeOverride::Auto {
+                let theme = system_theme();
+                apply(theme);
+                on_change(theme);
+            }
+        }))
+    }
+}
+
+fn dark_query() -> Option<MediaQueryList> {
+    web_sys::window()?.match_media(DARK_QUERY).ok().flatten()
+}
+
+fn system_theme() -> Theme {
+    match dark_query().map(|mq| mq.matches()) {
+        Some(true) => Theme::Dark,
+        _ => Theme::Light,
+    }
+}
+
+fn apply(theme: Theme) {
+    if let Some(body) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.body())
+    {
+        let _ = body.set_attribute("data-bs-theme", theme.as_attr());
+    }
+}
@@ -1,123 +1,65 @@
+use chrono::{DateTime, Local, NaiveTime, TimeZone, Utc};
 use gloo_net::http::Request;
-use gloo_console::log;
 use gloo_timers::future::TimeoutFuture;
 use futures::future::{select, Either};
 use serde::{Deserialize, Serialize};
 
+use crate::utils::decompress_if_needed;
+use crate::utils::logger::{log_weather, LogLevel};
+use crate::weather::icons::WeatherIcon;
+use crate::weather::models::{
+    AirQuality, CurrentConditions, DailyForecast, HourlyForecast, PrecipitationType, WeatherData,
+    WeatherWarning,
+};
+
 // Timeout for fetch in seconds
 const FETCH_TIMEOUT_SECS: u32 = 10;
 
 // Environment Canada GeoMet API - free, no auth, CORS enabled
-const WEATHER_API_URL: &str = "https://api.weather.gc.ca/collections/citypageweather-realtime/items?f=json&identifier=on-143";
-const AQHI_API_URL: &str = "https://api.weather.gc.ca/collections/aqhi-observations-realtime/items?f=json&location_id=FCWYG&sortby=-observation_datetime&limit=1";
+const WEATHER_API_BASE_URL: &str = "https://api.weather.gc.ca/collections/citypageweather-realtime/items?f=json";
+const AQHI_API_BASE_URL: &str = "https://api.weather.gc.ca/collections/aqhi-observations-realtime/items?f=json&sortby=-observation_datetime&limit=1";
+// Half-width in degrees of the box searched for an AQHI station around the city
+const AQHI_SEARCH_DEGREES: f64 = 0.5;
 
+/// Identifies an Environment Canada city page, e.g. `on-143` for Toronto
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct WeatherData {
-    pub current: CurrentConditions,
-    pub hourly: Vec<HourlyForecast>,
-    pub daily: Vec<DailyForecast>,
-    pub warnings: Vec<WeatherWarning>,
-    pub sun: Option<SunTimes>,
+pub struct EnvironmentCanadaClient {
+    pub province: String,
+    pub city_code: String,
 }
 
-impl WeatherData {
-    pub fn get_forecast_for_day(&self, day_name: &str) -> Option<&DailyForecast> {
-        self.daily.iter().find(|forecast| {
-            forecast.day_name.eq_ignore_ascii_case(day_name)
-        })
+impl EnvironmentCanadaClient {
+    pub fn new(province: &str, city_code: &str) -> Self {
+        Self {
+            province: province.trim().to_lowercase(),
+            city_code: city_code.trim().to_string(),
+        }
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct CurrentConditions {
-    pub temperature: f32,
-    pub condition: String,
-    pub icon: String,
-    pub humidity: u32,
-    pub wind_speed: u32,
-    pub wind_direction: String,
-    pub wind_gust: Option<u32>,
-    pub wind_chill: Option<i32>,
-    pub pressure: f32,
-    pub pressure_tendency: Option<String>,
-    pub dewpoint: f32,
-    pub visibility: Option<f32>,
-    pub station: String,
-    pub air_quality: Option<AirQuality>,
-}
-
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct AirQuality {
-    pub index: f32,
-    pub category: String,
-}
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct WeatherWarning {
-    pub description: String,
-    pub alert_level: String,
-    pub url: String,
-}
-
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct SunTimes {
-    pub sunrise: String,
-    pub sunset: String,
-}
+    pub fn toronto() -> Self {
+        Self::new("on", "143")
+    }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct HourlyForecast {
-    pub time: String,
-    pub temperature: i32,
-    pub condition: String,
-    pub pop: u32,
-    pub icon: String,
-    pub wind_speed: u32,
-    pub wind_direction: String,
-    pub wind_chill: Option<i32>,
-}
+    pub fn identifier(&self) -> String {
+        format!("{}-{}", self.province, self.city_code)
+    }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct DailyForecast {
-    pub day_name: String,
-    pub high: Option<i32>,
-    pub low: Option<i32>,
-    pub summary: String,
-    pub pop: Option<u32>,
-    pub icon: String,
-    pub uv_index: Option<String>,
-    pub wind_chill: Option<String>,
-    pub wind_summary: Option<String>,
+    fn weather_url(&self) -> String {
+        format!("{}&identifier={}", WEATHER_API_BASE_URL, self.identifier())
+    }
 }
 
-impl DailyForecast {
-    pub fn get_emoji(condition: &str) -> String {
-        let condition_lower = condition.to_lowercase();
-        if condition_lower.contains("sun") || condition_lower.contains("clear") {
-            "☀️".to_string()
-        } else if condition_lower.contains("cloud") && condition_lower.contains("sun") {
-            "⛅".to_string()
-        } else if condition_lower.contains("cloud") {
-            "☁️".to_string()
-        } else if condition_lower.contains("rain") || condition_lower.contains("shower") {
-            "🌧️".to_string()
-        } else if condition_lower.contains("snow") || condition_lower.contains("flurr") {
-            "❄️".to_string()
-        } else if condition_lower.contains("thunder") || condition_lower.contains("storm") {
-            "⛈️".to_string()
-        } else if condition_lower.contains("fog") || condition_lower.contains("mist") {
-            "🌫️".to_string()
-        } else {
-            "🌤️".to_string()
-        }
+impl Default for EnvironmentCanadaClient {
+    fn default() -> Self {
+        Self::toronto()
     }
 }
 
-pub async fn fetch_weather_data() -> Result<WeatherData, String> {
-    log!("Fetching weather from Environment Canada GeoMet API...");
+pub async fn fetch_weather_data(client: &EnvironmentCanadaClient) -> Result<WeatherData, String> {
+    log_weather(&format!("Fetching weather for {} from Environment Canada GeoMet API...", client.identifier()), LogLevel::Debug);
 
     // Race the fetch against a timeout
-    let fetch_future = Box::pin(fetch_and_parse());
+    let fetch_future = Box::pin(fetch_and_parse(client));
     let timeout_future = Box::pin(TimeoutFuture::new(FETCH_TIMEOUT_SECS * 1000));
 
     match select(fetch_future, timeout_future).await {
@@ -126,9 +68,9 @@ pub async fn fetch_weather_data() -> Result<WeatherData, String> {
     }
 }
 
-async fn fetch_and_parse() -> Result<WeatherData, String> {
+async fn fetch_and_parse(client: &EnvironmentCanadaClient) -> Result<WeatherData, String> {
     // Fetch main weather data
-    let response = Request::get(WEATHER_API_URL)
+    let response = Request::get(&client.weather_url())
         .send()
         .await
         .map_err(|e| format!("Network error: {:?}", e))?;
@@ -137,23 +79,51 @@ async fn fetch_and_parse() -> Result<WeatherData, String> {
         return Err(format!("HTTP {}: {}", response.status(), response.status_text()));
     }
 
-    let text = response
-        .text()
+    let bytes = response
+        .binary()
         .await
         .map_err(|e| format!("Failed to read response: {:?}", e))?;
+    let text = decompress_if_needed(&bytes)?;
 
     let mut weather_data = parse_api_response(&text)?;
 
-    // Try to fetch AQHI data (don't fail if unavailable)
-    if let Ok(aqhi) = fetch_aqhi().await {
-        weather_data.current.air_quality = Some(aqhi);
+    if let Some(ref t) = weather_data.current.pressure_tendency {
+        log_weather(&format!("Pressure tendency from API: '{}'", t), LogLevel::Trace);
+    }
+    log_weather(&format!("✓ Weather loaded: {}", weather_data.current), LogLevel::Info);
+
+    // Try to fetch AQHI data near the city (don't fail if unavailable). Cities
+    // with no station nearby simply get no badge.
+    if let Some((longitude, latitude)) = city_point(&text) {
+        if let Ok(aqhi) = fetch_aqhi(&aqhi_url(longitude, latitude)).await {
+            weather_data.current.air_quality = Some(aqhi);
+        }
     }
 
     Ok(weather_data)
 }
 
-async fn fetch_aqhi() -> Result<AirQuality, String> {
-    let response = Request::get(AQHI_API_URL)
+/// Longitude and latitude of the city page's station
+fn city_point(json_str: &str) -> Option<(f64, f64)> {
+    let json: serde_json::Value = serde_json::from_str(json_str).ok()?;
+    let coordinates = json.get("features")?.get(0)?.get("geometry")?.get("coordinates")?;
+    Some((coordinates.get(0)?.as_f64()?, coordinates.get(1)?.as_f64()?))
+}
+
+// Latest reading from any AQHI station in a box around the point
+fn aqhi_url(longitude: f64, latitude: f64) -> String {
+    format!(
+        "{}&bbox={:.2},{:.2},{:.2},{:.2}",
+        AQHI_API_BASE_URL,
+        longitude - AQHI_SEARCH_DEGREES,
+        latitude - AQHI_SEARCH_DEGREES,
+        longitude + AQHI_SEARCH_DEGREES,
+        latitude + AQHI_SEARCH_DEGREES,
+    )
+}
+
+async fn fetch_aqhi(url: &str) -> Result<AirQuality, String> {
+    let response = Request::get(url)
         .send()
         .await
         .map_err(|e| format!("AQHI network error: {:?}", e))?;
@@ -189,7 +159,8 @@ async fn fetch_aqhi() -> Result<AirQuality, String> {
     Ok(AirQuality { index, category })
 }
 
-fn parse_api_response(json_str: &str) -> Result<WeatherData, String> {
+// Pure parsing only: no logging or browser APIs, so it also runs natively (see benches/)
+pub fn parse_api_response(json_str: &str) -> Result<WeatherData, String> {
     let json: serde_json::Value = serde_json::from_str(json_str)
         .map_err(|e| format!("JSON parse error: {:?}", e))?;
 
@@ -211,28 +182,43 @@ fn parse_api_response(json_str: &str) -> Result<WeatherData, String> {
     let warnings = parse_warnings(props);
 
     // Parse sunrise/sunset
-    let sun = parse_sun_times(props);
+    let (sunrise, sunset) = parse_sun_times(props, &Local);
 
-    log!(&format!("✓ Weather loaded: {}°C, {}", current.temperature, current.condition));
+    let last_updated = props.get("lastUpdated")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string();
 
-    Ok(WeatherData {
+    let location = props.get("name")
+        .and_then(|n| n.get("en"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string();
+
+    let mut data = WeatherData {
         current,
         hourly,
         daily,
         warnings,
-        sun,
-    })
+        sunrise,
+        sunset,
+        last_updated,
+        location,
+    };
+    data.fill_pickup_feels_like();
+    Ok(data)
 }
 
 fn parse_current_conditions(props: &serde_json::Value) -> Result<CurrentConditions, String> {
     let cc = props.get("currentConditions")
         .ok_or("No currentConditions in response")?;
 
+    // Missing temperature stays NaN rather than masquerading as 0°C
     let temperature = cc.get("temperature")
         .and_then(|t| t.get("value"))
         .and_then(|v| v.get("en"))
         .and_then(|v| v.as_f64())
-        .unwrap_or(0.0) as f32;
+        .map_or(f32::NAN, |v| v as f32);
 
     let condition = cc.get("condition")
         .and_then(|c| c.get("en"))
@@ -275,6 +261,19 @@ fn parse_current_conditions(props: &serde_json::Value) -> Result<CurrentConditio
         .and_then(|v| v.as_i64())
         .map(|v| v as i32);
 
+    let humidex = cc.get("humidex")
+        .and_then(|h| h.get("value"))
+        .and_then(|v| v.get("en"))
+        .and_then(|v| v.as_f64())
+        .map(|v| v as f32);
+
+    let uv_index = cc.get("uv")
+        .and_then(|u| u.get("index"))
+        .and_then(|i| i.get("value"))
+        .and_then(|v| v.get("en"))
+        .and_then(|v| v.as_u64())
+        .map(|v| v as u32);
+
     let pressure = cc.get("pressure")
         .and_then(|p| p.get("value"))
         .and_then(|v| v.get("en"))
@@ -287,10 +286,6 @@ fn parse_current_conditions(props: &serde_json::Value) -> Result<CurrentConditio
         .and_then(|v| v.as_str())
         .map(|s| s.to_string());
 
-    if let Some(ref t) = pressure_tendency {
-        log!(&format!("Pressure tendency from API: '{}'", t));
-    }
-
     let dewpoint = cc.get("dewpoint")
         .and_then(|d| d.get("value"))
         .and_then(|v| v.get("en"))
@@ -310,7 +305,7 @@ fn parse_current_conditions(props: &serde_json::Value) -> Result<CurrentConditio
         .unwrap_or("")
         .to_string();
 
-    let icon = get_weather_icon(&condition);
+    let icon = WeatherIcon::from_condition(&condition).to_string();
 
     Ok(CurrentConditions {
         temperature,
@@ -321,6 +316,8 @@ fn parse_current_conditions(props: &serde_json::Value) -> Result<CurrentConditio
         wind_direction,
         wind_gust,
         wind_chill,
+        humidex,
+        uv_index,
         pressure,
         pressure_tendency,
         dewpoint,
@@ -353,11 +350,21 @@ fn parse_warnings(props: &serde_json::Value) -> Vec<WeatherWarning> {
                 .unwrap_or("")
                 .to_string();
 
+            // Older payloads lack `type`, so fall back to the wording of the title
+            let warning_type = w.get("type")
+                .and_then(|t| t.get("en"))
+                .and_then(|v| v.as_str())
+                .map(|t| t.to_lowercase())
+                .unwrap_or_else(|| warning_type_from_title(&description));
+            let priority = warning_priority(&warning_type);
+
             if !description.is_empty() {
                 warnings.push(WeatherWarning {
                     description,
                     alert_level,
                     url,
+                    warning_type,
+                    priority,
                 });
             }
         }
@@ -366,39 +373,108 @@ fn parse_warnings(props: &serde_json::Value) -> Vec<WeatherWarning> {
     warnings
 }
 
-fn parse_sun_times(props: &serde_json::Value) -> Option<SunTimes> {
-    let rise_set = props.get("riseSet")?;
+fn warning_type_from_title(title: &str) -> String {
+    let upper = title.to_uppercase();
+    if upper.contains("WARNING") {
+        "warning"
+    } else if upper.contains("WATCH") {
+        "watch"
+    } else if upper.contains("ADVISORY") {
+        "advisory"
+    } else {
+        // Covers "SPECIAL WEATHER STATEMENT" and anything unrecognised
+        "statement"
+    }
+    .to_string()
+}
+
+fn warning_priority(warning_type: &str) -> String {
+    match warning_type {
+        "warning" => "high",
+        "watch" => "medium",
+        _ => "low",
+    }
+    .to_string()
+}
 
-    let sunrise_utc = rise_set.get("sunrise")
-        .and_then(|s| s.get("en"))
-        .and_then(|v| v.as_str())?;
+/// Sunrise and sunset as wall-clock times in `tz` (`Local` outside tests)
+fn parse_sun_times<Tz: TimeZone>(props: &serde_json::Value, tz: &Tz) -> (Option<NaiveTime>, Option<NaiveTime>) {
+    let rise_set = props.get("riseSet");
+    let time_of = |key: &str| {
+        rise_set
+            .and_then(|r| r.get(key))
+            .and_then(|s| s.get("en"))
+            .and_then(|v| v.as_str())
+            .and_then(|utc| utc_to_time(utc, tz))
+    };
+
+    (time_of("sunrise"), time_of("sunset"))
+}
 
-    let sunset_utc = rise_set.get("sunset")
-        .and_then(|s| s.get("en"))
-        .and_then(|v| v.as_str())?;
+// "2026-01-30T12:37:00Z" as the time of day in `tz`, DST included
+fn utc_to_time<Tz: TimeZone>(utc: &str, tz: &Tz) -> Option<NaiveTime> {
+    utc.parse::<DateTime<Utc>>()
+        .ok()
+        .map(|t| t.with_timezone(tz).time())
+}
 
-    // Convert UTC to local time display (just extract time part for now)
-    let sunrise = format_utc_to_local_time(sunrise_utc);
-    let sunset = format_utc_to_local_time(sunset_utc);
+/// Maps a forecast period to the day it belongs to, and whether it is the
+/// night half: "Tonight" and "Overnight" belong to today, "Sunday night" to Sunday.
+fn period_day(period: &str) -> (String, bool) {
+    let lower = period.to_lowercase();
+    if lower == "tonight" || lower == "overnight" {
+        ("Today".to_string(), true)
+    } else if let Some(day) = lower.strip_suffix(" night") {
+        (period[..day.len()].to_string(), true)
+    } else {
+        (period.to_string(), false)
+    }
+}
 
-    Some(SunTimes { sunrise, sunset })
+// One day being assembled from its day and night periods
+struct DayParts {
+    day_name: String,
+    high: Option<i32>,
+    low: Option<i32>,
+    day_summary: Option<String>,
+    night_summary: Option<String>,
+    pop: Option<u32>,
+    uv_index: Option<String>,
+    wind_chill: Option<String>,
+    wind_summary: Option<String>,
 }
 
-fn format_utc_to_local_time(utc_str: &str) -> String {
-    // UTC format: "2026-01-30T12:37:00Z"
-    // Toronto is UTC-5, so subtract 5 hours
-    if let Some(time_part) = utc_str.split('T').nth(1) {
-        if let Some(hour_str) = time_part.split(':').next() {
-            if let Ok(hour) = hour_str.parse::<i32>() {
-                let local_hour = (hour - 5 + 24) % 24;
-                let minute = time_part.split(':').nth(1).unwrap_or("00");
-                let am_pm = if local_hour < 12 { "AM" } else { "PM" };
-                let display_hour = if local_hour == 0 { 12 } else if local_hour > 12 { local_hour - 12 } else { local_hour };
-                return format!("{}:{} {}", display_hour, minute, am_pm);
-            }
+impl DayParts {
+    fn new(day_name: String) -> Self {
+        Self {
+            day_name,
+            high: None,
+            low: None,
+            day_summary: None,
+            night_summary: None,
+            pop: None,
+            uv_index: None,
+            wind_chill: None,
+            wind_summary: None,
+        }
+    }
+
+    fn into_forecast(self) -> DailyForecast {
+        // A day fetched in the evening only has its night half left
+        let summary = self.day_summary.or(self.night_summary).unwrap_or_default();
+        DailyForecast {
+            icon: WeatherIcon::from_condition(&summary).to_string(),
+            day_name: self.day_name,
+            high: self.high,
+            low: self.low,
+            summary,
+            pop: self.pop,
+            uv_index: self.uv_index,
+            wind_chill: self.wind_chill,
+            wind_summary: self.wind_summary,
+            pickup_feels_like: None,
         }
     }
-    utc_str.to_string()
 }
 
 fn parse_forecasts(props: &serde_json::Value) -> (Vec<HourlyForecast>, Vec<DailyForecast>) {
@@ -413,8 +489,12 @@ fn parse_forecasts(props: &serde_json::Value) -> (Vec<HourlyForecast>, Vec<Daily
                     .and_then(|t| t.as_str())
                     .unwrap_or("");
 
-                // Extract hour from timestamp for display
-                let time = format_utc_to_local_time(timestamp);
+                let parsed_time = DateTime::parse_from_rfc3339(timestamp)
+                    .ok()
+                    .map(|t| t.with_timezone(&Local).naive_local());
+                // Hour label for the chart, e.g. "3:00 PM"
+                let time = parsed_time
+                    .map_or_else(|| timestamp.to_string(), |t| t.format("%-I:%M %p").to_string());
 
                 let temperature = fc.get("temperature")
                     .and_then(|t| t.get("value"))
@@ -454,7 +534,8 @@ fn parse_forecasts(props: &serde_json::Value) -> (Vec<HourlyForecast>, Vec<Daily
                     .and_then(|v| v.as_i64())
                     .map(|v| v as i32);
 
-                let icon = DailyForecast::get_emoji(&condition);
+                let icon = WeatherIcon::from_condition(&condition).to_string();
+                let precipitation_type = PrecipitationType::from_condition(&condition);
 
                 hourly.push(HourlyForecast {
                     time,
@@ -465,6 +546,8 @@ fn parse_forecasts(props: &serde_json::Value) -> (Vec<HourlyForecast>, Vec<Daily
                     wind_speed,
                     wind_direction,
                     wind_chill,
+                    parsed_time,
+                    precipitation_type,
                 });
             }
         }
@@ -473,7 +556,8 @@ fn parse_forecasts(props: &serde_json::Value) -> (Vec<HourlyForecast>, Vec<Daily
     // Parse daily forecasts from forecastGroup
     if let Some(fg) = props.get("forecastGroup") {
         if let Some(forecasts) = fg.get("forecasts").and_then(|f| f.as_array()) {
-            let mut day_forecasts: std::collections::HashMap<String, (Option<i32>, Option<i32>, String, Option<u32>, Option<String>, Option<String>, Option<String>)> = std::collections::HashMap::new();
+            // Periods arrive in order, each night straight after its day
+            let mut days: Vec<DayParts> = Vec::new();
 
             for fc in forecasts {
                 let period = fc.get("period")
@@ -535,79 +619,37 @@ fn parse_forecasts(props: &serde_json::Value) -> (Vec<HourlyForecast>, Vec<Daily
                     .and_then(|v| v.as_str())
                     .map(|s| s.to_string());
 
-                // Build daily forecasts by combining day/night
-                let is_night = period.to_lowercase().contains("night") ||
-                               period.to_lowercase().contains("tonight");
-
-                let day_name = if is_night {
-                    period.replace(" night", "").replace("tonight", "Friday")
-                } else {
-                    period.to_string()
-                };
-
-                let entry = day_forecasts.entry(day_name.clone()).or_insert((None, None, String::new(), None, None, None, None));
+                let (day_name, is_night) = period_day(period);
 
-                if temp_class == "high" || !is_night {
-                    entry.0 = temp; // high
-                    if entry.2.is_empty() {
-                        entry.2 = summary;
-                    }
-                    // Prefer daytime UV/wind info
-                    if uv_index.is_some() {
-                        entry.4 = uv_index;
-                    }
-                    if wind_summary.is_some() {
-                        entry.6 = wind_summary;
+                // Start a new day unless this is the night half of the last one
+                if days.last().is_none_or(|day| day.day_name != day_name) {
+                    days.push(DayParts::new(day_name));
+                }
+                let Some(day) = days.last_mut() else { continue };
+
+                if is_night {
+                    // A "high" overnight means temperatures rise through the night
+                    if temp_class == "high" {
+                        day.high = day.high.or(temp);
+                    } else {
+                        day.low = temp;
                     }
+                    day.night_summary = Some(summary);
+                    day.wind_chill = day.wind_chill.take().or(wind_chill);
                 } else {
-                    entry.1 = temp; // low
-                }
-                if pop > 0 && entry.3.unwrap_or(0) < pop {
-                    entry.3 = Some(pop);
+                    day.high = temp;
+                    day.day_summary = Some(summary);
+                    // Prefer daytime UV/wind info
+                    day.uv_index = uv_index;
+                    day.wind_summary = wind_summary;
+                    day.wind_chill = wind_chill.or(day.wind_chill.take());
                 }
-                // Wind chill from either day or night
-                if wind_chill.is_some() && entry.5.is_none() {
-                    entry.5 = wind_chill;
+                if pop > 0 && day.pop.unwrap_or(0) < pop {
+                    day.pop = Some(pop);
                 }
             }
 
-            // Convert to daily forecasts (preserve order)
-            let mut seen_days = std::collections::HashSet::new();
-            for fc in forecasts {
-                let period = fc.get("period")
-                    .and_then(|p| p.get("value"))
-                    .and_then(|v| v.get("en"))
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("");
-
-                let is_night = period.to_lowercase().contains("night") ||
-                               period.to_lowercase().contains("tonight");
-                let day_name = if is_night {
-                    period.replace(" night", "").replace("tonight", "Friday")
-                } else {
-                    period.to_string()
-                };
-
-                if seen_days.contains(&day_name) {
-                    continue;
-                }
-                seen_days.insert(day_name.clone());
-
-                if let Some((high, low, summary, pop, uv_index, wind_chill, wind_summary)) = day_forecasts.get(&day_name) {
-                    let icon = DailyForecast::get_emoji(summary);
-                    daily.push(DailyForecast {
-                        day_name,
-                        high: *high,
-                        low: *low,
-                        summary: summary.clone(),
-                        pop: *pop,
-                        icon,
-                        uv_index: uv_index.clone(),
-                        wind_chill: wind_chill.clone(),
-                        wind_summary: wind_summary.clone(),
-                    });
-                }
-            }
+            daily.extend(days.into_iter().map(DayParts::into_forecast));
         }
     }
 
@@ -630,23 +672,226 @@ fn extract_pop(text: &str) -> u32 {
     0
 }
 
-fn get_weather_icon(condition: &str) -> String {
-    let condition_lower = condition.to_lowercase();
-    if condition_lower.contains("sun") || condition_lower.contains("clear") {
-        "☀️".to_string()
-    } else if condition_lower.contains("cloud") && (condition_lower.contains("sun") || condition_lower.contains("clear")) {
-        "⛅".to_string()
-    } else if condition_lower.contains("cloud") {
-        "☁️".to_string()
-    } else if condition_lower.contains("rain") || condition_lower.contains("shower") || condition_lower.contains("drizzle") {
-        "🌧️".to_string()
-    } else if condition_lower.contains("snow") || condition_lower.contains("flurr") {
-        "❄️".to_string()
-    } else if condition_lower.contains("thunder") || condition_lower.contains("storm") {
-        "⛈️".to_string()
-    } else if condition_lower.contains("fog") || condition_lower.contains("mist") {
-        "🌫️".to_string()
-    } else {
-        "🌤️".to_string()
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+
+    const TORONTO: &str = include_str!("../../benches/fixtures/citypageweather_on-143.json");
+
+    #[test]
+    fn client_fetches_the_chosen_city() {
+        let client = EnvironmentCanadaClient::new(" BC ", "74 ");
+
+        assert_eq!(client.identifier(), "bc-74");
+        assert!(client.weather_url().ends_with("&identifier=bc-74"));
+        assert_eq!(EnvironmentCanadaClient::default().identifier(), "on-143");
+    }
+
+    #[test]
+    fn aqhi_is_looked_up_around_the_city() {
+        let (longitude, latitude) = city_point(TORONTO).unwrap();
+
+        assert_eq!((longitude, latitude), (-79.4, 43.74));
+        assert!(aqhi_url(longitude, latitude).ends_with("&bbox=-79.90,43.24,-78.90,44.24"));
+    }
+
+    #[test]
+    fn no_aqhi_lookup_without_a_city_point() {
+        assert_eq!(city_point(r#"{"features":[{"properties":{}}]}"#), None);
+        assert_eq!(city_point("not json"), None);
+    }
+
+    #[test]
+    fn parses_current_conditions() {
+        let current = parse_api_response(TORONTO).unwrap().current;
+
+        assert_eq!(current.temperature, 10.4);
+        assert_eq!(current.condition, "Mainly Cloudy");
+        assert_eq!(current.humidity, 74);
+        assert_eq!((current.wind_speed, current.wind_direction.as_str(), current.wind_gust), (18, "SW", Some(32)));
+        assert_eq!(current.wind_chill, None);
+        assert_eq!(current.pressure, 101.3);
+        assert_eq!(current.pressure_tendency.as_deref(), Some("rising"));
+        assert_eq!(current.visibility, Some(24.1));
+        assert_eq!(current.station, "Toronto Pearson Int'l Airport");
+    }
+
+    #[test]
+    fn parses_hourly_forecasts() {
+        let hourly = parse_api_response(TORONTO).unwrap().hourly;
+
+        assert_eq!(hourly.len(), 24);
+        assert_eq!((hourly[0].temperature, hourly[0].pop, hourly[0].wind_speed), (12, 10, 15));
+        assert_eq!(hourly[0].precipitation_type, None);
+        assert_eq!(hourly[1].condition, "Chance of showers");
+        assert_eq!(hourly[1].precipitation_type, Some(PrecipitationType::Rain));
+        assert!(hourly.iter().all(|h| h.parsed_time.is_some()));
+    }
+
+    #[test]
+    fn parses_daily_forecasts_one_per_day() {
+        let daily = parse_api_response(TORONTO).unwrap().daily;
+
+        // 14 day and night periods fold into 7 days
+        assert_eq!(daily.len(), 7);
+        assert_eq!(daily[0].day_name, "Today");
+        assert_eq!((daily[0].high, daily[0].low, daily[0].pop), (Some(12), Some(4), Some(30)));
+        assert_eq!(daily[0].uv_index.as_deref(), Some("UV index 3 or moderate."));
+        assert_eq!(daily[1].day_name, "Friday");
+        assert_eq!(daily[1].wind_chill.as_deref(), Some("Wind chill minus 5 overnight."));
+        assert_eq!(daily[2].low, Some(-1));
+    }
+
+    #[test]
+    fn parses_warnings_sun_and_metadata() {
+        let data = parse_api_response(TORONTO).unwrap();
+
+        assert_eq!(data.warnings.len(), 1);
+        assert_eq!(data.warnings[0].description, "Special weather statement in effect");
+        assert_eq!((data.warnings[0].alert_level.as_str(), data.warnings[0].priority.as_str()), ("yellow", "low"));
+        // Stored in the host's local time, whatever zone the tests run in
+        let sunrise = Utc.with_ymd_and_hms(2025, 10, 16, 11, 26, 0).unwrap().with_timezone(&Local).time();
+        assert_eq!(data.sunrise, Some(sunrise));
+        assert!(data.sunset.is_some());
+        assert_eq!(data.last_updated, "2025-10-16T15:00:00Z");
+        assert_eq!(data.location, "Toronto");
+    }
+
+    #[test]
+    fn sun_times_follow_daylight_saving() {
+        let props: serde_json::Value = serde_json::from_str(TORONTO).unwrap();
+        let props = &props["features"][0]["properties"];
+        let edt = FixedOffset::west_opt(4 * 3600).unwrap();
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0);
+
+        // Mid-October Toronto is on EDT (UTC-4), not UTC-5
+        assert_eq!(parse_sun_times(props, &edt), (time(7, 26), time(18, 32)));
+        // A July sunrise
+        assert_eq!(utc_to_time("2025-07-01T09:36:00Z", &edt), time(5, 36));
+        assert_eq!(utc_to_time("not a time", &edt), None);
+    }
+
+    #[test]
+    fn warning_type_and_priority_come_from_the_title() {
+        let classify = |title| {
+            let warning_type = warning_type_from_title(title);
+            let priority = warning_priority(&warning_type);
+            (warning_type, priority)
+        };
+
+        assert_eq!(classify("Snowfall WARNING in effect"), ("warning".into(), "high".into()));
+        assert_eq!(classify("Severe thunderstorm watch in effect"), ("watch".into(), "medium".into()));
+        assert_eq!(classify("Fog advisory in effect"), ("advisory".into(), "low".into()));
+        assert_eq!(classify("SPECIAL WEATHER STATEMENT in effect"), ("statement".into(), "low".into()));
+    }
+
+    #[test]
+    fn warning_type_from_the_payload_wins_over_the_title() {
+        let json = r#"{"features":[{"properties":{
+            "currentConditions":{},
+            "warnings":[{"description":{"en":"Heat event in effect"},"type":{"en":"Warning"}}]
+        }}]}"#;
+        let warnings = parse_api_response(json).unwrap().warnings;
+
+        assert_eq!((warnings[0].warning_type.as_str(), warnings[0].priority.as_str()), ("warning", "high"));
+    }
+
+    #[test]
+    fn night_periods_map_to_their_day() {
+        assert_eq!(period_day("Tonight"), ("Today".to_string(), true));
+        assert_eq!(period_day("Overnight"), ("Today".to_string(), true));
+        assert_eq!(period_day("Sunday night"), ("Sunday".to_string(), true));
+        assert_eq!(period_day("Sunday"), ("Sunday".to_string(), false));
+        assert_eq!(period_day("Today"), ("Today".to_string(), false));
+    }
+
+    // forecastGroup with one (period, temperature class, temperature) per entry
+    fn periods(entries: &[(&str, &str, i32)]) -> serde_json::Value {
+        let forecasts: Vec<_> = entries.iter()
+            .map(|(period, class, temp)| serde_json::json!({
+                "period": {"value": {"en": period}},
+                "abbreviatedForecast": {"textSummary": {"en": format!("{} sky", period)}},
+                "temperatures": {"temperature": [{"class": {"en": class}, "value": {"en": temp}}]},
+            }))
+            .collect();
+        serde_json::json!({"forecastGroup": {"forecasts": forecasts}})
+    }
+
+    #[test]
+    fn evening_fetch_pairs_each_night_with_its_day() {
+        let (hourly, daily) = parse_forecasts(&periods(&[
+            ("Tonight", "low", 3),
+            ("Friday", "high", 11),
+            ("Friday night", "low", 2),
+            ("Saturday", "high", 9),
+        ]));
+
+        assert!(hourly.is_empty());
+        let days: Vec<_> = daily.iter().map(|d| (d.day_name.as_str(), d.high, d.low)).collect();
+        assert_eq!(days, [("Today", None, Some(3)), ("Friday", Some(11), Some(2)), ("Saturday", Some(9), None)]);
+        // Today only has its night left, so that summary stands in
+        assert_eq!(daily[0].summary, "Tonight sky");
+        assert_eq!(daily[1].summary, "Friday sky");
+    }
+
+    #[test]
+    fn overnight_and_rising_temperatures_stay_on_today() {
+        let (_, daily) = parse_forecasts(&periods(&[
+            ("Today", "high", 5),
+            ("Overnight", "high", 7),
+            ("Sunday", "high", 8),
+        ]));
+
+        assert_eq!(daily.len(), 2);
+        // A "high" at night doesn't replace the daytime high or count as a low
+        assert_eq!((daily[0].high, daily[0].low), (Some(5), None));
+        assert_eq!(daily[1].day_name, "Sunday");
+    }
+
+    #[test]
+    fn negative_temperatures_keep_their_sign() {
+        let json = r#"{"features":[{"properties":{
+            "currentConditions":{"temperature":{"value":{"en":-3.5}},"windChill":{"value":{"en":-11}}},
+            "hourlyForecastGroup":{"hourlyForecasts":[{"temperature":{"value":{"en":-5}},"windChill":{"value":{"en":-12}}}]}
+        }}]}"#;
+        let data = parse_api_response(json).unwrap();
+
+        assert_eq!((data.current.temperature, data.current.wind_chill), (-3.5, Some(-11)));
+        assert_eq!((data.hourly[0].temperature, data.hourly[0].wind_chill), (-5, Some(-12)));
+    }
+
+    #[test]
+    fn pop_is_the_number_before_percent() {
+        assert_eq!(extract_pop("Periods of rain. 60 percent chance of showers."), 60);
+        assert_eq!(extract_pop("Chance of flurries. 30 Percent chance."), 30);
+        assert_eq!(extract_pop("Sunny. High plus 12."), 0);
+    }
+
+    #[test]
+    fn missing_current_conditions_is_an_error() {
+        assert!(parse_api_response(r#"{"features":[{"properties":{}}]}"#).is_err());
+        assert!(parse_api_response(r#"{"features":[]}"#).is_err());
+        assert!(parse_api_response("{}").is_err());
+    }
+
+    #[test]
+    fn null_fields_fall_back_to_defaults() {
+        let json = r#"{"features":[{"properties":{
+            "currentConditions":{"temperature":{"value":{"en":null}},"wind":null,"relativeHumidity":null},
+            "riseSet":null,
+            "forecastGroup":null,
+            "hourlyForecastGroup":{"hourlyForecasts":null},
+            "warnings":null
+        }}]}"#;
+        let data = parse_api_response(json).unwrap();
+
+        assert!(data.current.temperature.is_nan());
+        assert_eq!((data.current.humidity, data.current.wind_speed, data.current.wind_gust), (0, 0, None));
+        assert!(data.hourly.is_empty());
+        assert!(data.daily.is_empty());
+        assert!(data.warnings.is_empty());
+        assert_eq!((data.sunrise, data.sunset), (None, None));
+        assert_eq!(data.location, "");
     }
 }
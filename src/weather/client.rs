@@ -0,0 +1,23 @@
+// src/weather/client.rs
+use crate::weather::api::{fetch_weather_data, EnvironmentCanadaClient};
+use crate::weather::WeatherData;
+
+/// A source `WeatherProvider` can fetch from
+// Everything runs on the single wasm thread, so the futures don't need `Send`
+#[allow(async_fn_in_trait)]
+pub trait WeatherClient: Clone + PartialEq {
+    /// Stable id for this source, used to match cached data to it
+    fn identifier(&self) -> String;
+
+    async fn fetch_weather(&self) -> Result<WeatherData, String>;
+}
+
+impl WeatherClient for EnvironmentCanadaClient {
+    fn identifier(&self) -> String {
+        EnvironmentCanadaClient::identifier(self)
+    }
+
+    async fn fetch_weather(&self) -> Result<WeatherData, String> {
+        fetch_weather_data(self).await
+    }
+}
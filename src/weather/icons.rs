@@ -0,0 +1,97 @@
+// src/weather/icons.rs
+use std::fmt;
+
+/// Icon shown for a forecast condition, picked from Environment Canada's
+/// free-text condition ("Mainly sunny", "Chance of showers", ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeatherIcon {
+    Sunny,
+    PartlyCloudy,
+    Cloudy,
+    Rain,
+    Snow,
+    Thunderstorm,
+    Fog,
+    Default,
+}
+
+impl WeatherIcon {
+    pub fn from_condition(condition: &str) -> Self {
+        let c = condition.to_lowercase();
+
+        // Most specific first: "sunny with cloudy periods" must not land on Sunny
+        if c.contains("thunder") || c.contains("storm") {
+            Self::Thunderstorm
+        } else if c.contains("snow") || c.contains("flurr") {
+            Self::Snow
+        } else if c.contains("rain") || c.contains("shower") || c.contains("drizzle") {
+            Self::Rain
+        } else if c.contains("fog") || c.contains("mist") {
+            Self::Fog
+        } else if c.contains("cloud") && (c.contains("sun") || c.contains("clear") || c.contains("partly"))
+            || c.contains("mix")
+        {
+            Self::PartlyCloudy
+        } else if c.contains("cloud") {
+            Self::Cloudy
+        } else if c.contains("sun") || c.contains("clear") {
+            Self::Sunny
+        } else {
+            Self::Default
+        }
+    }
+}
+
+// Escaped codepoints so the icons survive editors that re-save with a legacy encoding
+impl fmt::Display for WeatherIcon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let emoji = match self {
+            Self::Sunny => "\u{2600}\u{FE0F}",           // ☀️
+            Self::PartlyCloudy => "\u{26C5}",            // ⛅
+            Self::Cloudy => "\u{2601}\u{FE0F}",          // ☁️
+            Self::Rain => "\u{1F327}\u{FE0F}",           // 🌧️
+            Self::Snow => "\u{2744}\u{FE0F}",            // ❄️
+            Self::Thunderstorm => "\u{26C8}\u{FE0F}",    // ⛈️
+            Self::Fog => "\u{1F32B}\u{FE0F}",            // 🌫️
+            Self::Default => "\u{1F324}\u{FE0F}",        // 🌤️
+        };
+        f.write_str(emoji)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn icons_display_as_real_emoji() {
+        assert_eq!(WeatherIcon::from_condition("sunny").to_string(), "☀️");
+        assert_eq!(WeatherIcon::PartlyCloudy.to_string(), "⛅");
+        assert_eq!(WeatherIcon::Rain.to_string(), "🌧️");
+        // None of them is mojibake from a Latin-1 round trip
+        assert!(!WeatherIcon::Sunny.to_string().contains('â'));
+    }
+
+    #[test]
+    fn most_specific_condition_wins() {
+        let cases = [
+            ("Sunny", WeatherIcon::Sunny),
+            ("Clear", WeatherIcon::Sunny),
+            ("Sunny with cloudy periods", WeatherIcon::PartlyCloudy),
+            ("A mix of sun and cloud", WeatherIcon::PartlyCloudy),
+            ("Mainly Cloudy", WeatherIcon::Cloudy),
+            ("Chance of showers", WeatherIcon::Rain),
+            ("Periods of drizzle", WeatherIcon::Rain),
+            ("Rain mixed with snow", WeatherIcon::Snow),
+            ("Flurries", WeatherIcon::Snow),
+            ("Showers or thunderstorms", WeatherIcon::Thunderstorm),
+            ("Fog patches", WeatherIcon::Fog),
+            ("Haze", WeatherIcon::Default),
+            ("", WeatherIcon::Default),
+        ];
+
+        for (condition, icon) in cases {
+            assert_eq!(WeatherIcon::from_condition(condition), icon, "{}", condition);
+        }
+    }
+}
@@ -1,2 +1,12 @@
 // src/weather/mod.rs
 pub mod api;
+pub mod client;
+pub mod icons;
+pub mod models;
+pub mod openmeteo;
+pub mod test_data;
+
+pub use models::{
+    ComfortLevel, CurrentConditions, HumidexComfortLevel, WindChillWarning, DailyForecast, HourlyForecast, PrecipitationType, WeatherData, WeatherHourly,
+    WeatherTrend, WeatherWarning,
+};
@@ -1,95 +1,737 @@
 // src/weather/models.rs
+//
+// Weather data shared by every source (Environment Canada, Open-Meteo, mock
+// data) and the components that render it.
+use std::fmt;
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+// Start of `WeatherData::location` when the data came from the Open-Meteo fallback
+pub const FALLBACK_LOCATION_PREFIX: &str = "Open-Meteo fallback";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WeatherData {
-    pub location: String,
     pub current: CurrentConditions,
-    pub forecasts: Vec<DailyForecast>,
+    pub hourly: Vec<HourlyForecast>,
+    pub daily: Vec<DailyForecast>,
     pub warnings: Vec<WeatherWarning>,
+    /// Today's sunrise and sunset, in the device's local time
+    #[serde(default)]
+    pub sunrise: Option<NaiveTime>,
+    #[serde(default)]
+    pub sunset: Option<NaiveTime>,
+    /// RFC 3339 timestamp of when Environment Canada issued this data
+    #[serde(default)]
     pub last_updated: String,
+    /// City name, or a fallback label when Environment Canada was unreachable
+    #[serde(default)]
+    pub location: String,
+}
+
+// Placeholder state: shows "—" and "Never" rather than blank text
+impl Default for WeatherData {
+    fn default() -> Self {
+        Self {
+            current: CurrentConditions::default(),
+            hourly: Vec::new(),
+            daily: Vec::new(),
+            warnings: Vec::new(),
+            sunrise: None,
+            sunset: None,
+            last_updated: "Never".to_string(),
+            location: "—".to_string(),
+        }
+    }
+}
+
+impl WeatherData {
+    /// Missing or unparseable timestamps count as stale so they get refreshed
+    pub fn is_stale(&self, max_age: Duration) -> bool {
+        match DateTime::parse_from_rfc3339(&self.last_updated) {
+            Ok(updated) => {
+                let age_ms = js_sys::Date::now() - updated.timestamp_millis() as f64;
+                age_ms > max_age.as_millis() as f64
+            }
+            Err(_) => true,
+        }
+    }
+
+    /// Matches full or abbreviated names either way round, e.g. "Mon" finds "Monday"
+    pub fn get_forecast_for_day(&self, day_name: &str) -> Option<&DailyForecast> {
+        let day_name = normalize_day_name(day_name);
+        self.daily.iter().find(|forecast| {
+            normalize_day_name(&forecast.day_name).eq_ignore_ascii_case(day_name)
+        })
+    }
+
+    /// True when the day's chance of precipitation is at least `threshold_percent`
+    pub fn has_precipitation_risk(&self, day_name: &str, threshold_percent: u32) -> bool {
+        self.get_forecast_for_day(day_name)
+            .and_then(|forecast| forecast.pop)
+            .is_some_and(|pop| pop >= threshold_percent)
+    }
+
+    /// True when the day's low is below freezing; an unknown low is not frost
+    pub fn has_overnight_frost(&self, day_name: &str) -> bool {
+        self.get_forecast_for_day(day_name)
+            .is_some_and(|forecast| forecast.low.is_some_and(|low| low < 0))
+    }
+
+    /// Compares the first and second half of the next six hours
+    pub fn hourly_trend(&self) -> WeatherTrend {
+        let hours = &self.hourly[..self.hourly.len().min(TREND_HOURS)];
+        if hours.len() < 2 {
+            return WeatherTrend::Stable;
+        }
+        let (early, late) = hours.split_at(hours.len() / 2);
+        let average = |forecasts: &[HourlyForecast], value: fn(&HourlyForecast) -> f32| {
+            forecasts.iter().map(value).sum::<f32>() / forecasts.len() as f32
+        };
+
+        let pop_change = average(late, |f| f.pop as f32) - average(early, |f| f.pop as f32);
+        let temp_change = average(late, |f| f.temperature as f32) - average(early, |f| f.temperature as f32);
+
+        if pop_change > TREND_POP_CHANGE || temp_change < -TREND_TEMP_DROP {
+            WeatherTrend::Worsening
+        } else if pop_change < -TREND_POP_CHANGE && temp_change > 0.0 {
+            WeatherTrend::Improving
+        } else {
+            WeatherTrend::Stable
+        }
+    }
+
+    /// Hourly forecasts starting within `start..=end`
+    pub fn get_hourly_for_time_range(&self, start: DateTime<Local>, end: DateTime<Local>) -> Vec<&HourlyForecast> {
+        let (start, end) = (start.naive_local(), end.naive_local());
+        self.hourly.iter()
+            .filter(|forecast| forecast.parsed_time.is_some_and(|t| t >= start && t <= end))
+            .collect()
+    }
+
+    /// Fills each day's `pickup_feels_like` from the hourly forecast
+    pub fn fill_pickup_feels_like(&mut self) {
+        let today = Local::now().date_naive();
+        let feels_like: Vec<Option<f32>> = self.daily.iter()
+            .map(|forecast| {
+                let date = forecast_date(&forecast.day_name, today)?;
+                let window = |hour| date.and_hms_opt(hour, 0, 0)?.and_local_timezone(Local).single();
+                self.get_hourly_for_time_range(window(PICKUP_MORNING_START_HOUR)?, window(PICKUP_MORNING_END_HOUR)?)
+                    .into_iter()
+                    .min_by_key(|f| f.parsed_time.map(|t| t.hour().abs_diff(PICKUP_FEELS_LIKE_HOUR)))
+                    .map(HourlyForecast::feels_like)
+            })
+            .collect();
+
+        for (forecast, feels_like) in self.daily.iter_mut().zip(feels_like) {
+            forecast.pickup_feels_like = feels_like;
+        }
+    }
+
+    pub fn is_fallback(&self) -> bool {
+        self.location.starts_with(FALLBACK_LOCATION_PREFIX)
+    }
+
+    pub fn active_alerts_count(&self) -> usize {
+        self.warnings.len()
+    }
+
+    pub fn has_severe_warnings(&self) -> bool {
+        self.warnings.iter().any(|w| w.priority == "high")
+    }
+}
+
+impl AsRef<CurrentConditions> for WeatherData {
+    fn as_ref(&self) -> &CurrentConditions {
+        &self.current
+    }
+}
+
+impl From<&WeatherData> for Vec<DailyForecast> {
+    fn from(weather: &WeatherData) -> Self {
+        weather.daily.clone()
+    }
+}
+
+const DAY_NAMES: [(&str, &str); 7] = [
+    ("Mon", "monday"),
+    ("Tue", "tuesday"),
+    ("Wed", "wednesday"),
+    ("Thu", "thursday"),
+    ("Fri", "friday"),
+    ("Sat", "saturday"),
+    ("Sun", "sunday"),
+];
+
+/// Canonical short form of a day name: "Monday", "mon" and "Mon." all give
+/// "Mon", and a night period such as "Saturday night" gives its day, "Sat".
+/// "Tonight" and "Overnight" are today's night half, so they give "Today".
+/// Anything else comes back unchanged.
+pub fn normalize_day_name(name: &str) -> &str {
+    let trimmed = name.trim().trim_end_matches('.');
+    let trimmed = match trimmed.rsplit_once(' ') {
+        Some((day, night)) if night.eq_ignore_ascii_case("night") => day.trim_end(),
+        _ => trimmed,
+    };
+    if ["today", "tonight", "overnight"].iter().any(|d| trimmed.eq_ignore_ascii_case(d)) {
+        return "Today";
+    }
+    // Any prefix of at least three letters, so "Thurs" works too
+    DAY_NAMES.iter()
+        .find(|(_, full)| {
+            trimmed.len() >= 3
+                && trimmed.len() <= full.len()
+                && full[..trimmed.len()].eq_ignore_ascii_case(trimmed)
+        })
+        .map_or(name, |(short, _)| short)
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+// Pickup morning window `fill_pickup_feels_like` reads the hourly forecast for
+const PICKUP_MORNING_START_HOUR: u32 = 7;
+const PICKUP_MORNING_END_HOUR: u32 = 9;
+const PICKUP_FEELS_LIKE_HOUR: u32 = 8;
+
+// Next date a daily forecast's day name ("Today", "Friday"...) refers to
+fn forecast_date(day_name: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let day_name = normalize_day_name(day_name);
+    if day_name == "Today" {
+        return Some(today);
+    }
+    let weekday = day_name.parse::<Weekday>().ok()?;
+    let days_ahead = (weekday.num_days_from_monday() + 7 - today.weekday().num_days_from_monday()) % 7;
+    Some(today + chrono::Duration::days(days_ahead as i64))
+}
+
+// Hours of hourly forecast `hourly_trend` looks at, split into two halves
+const TREND_HOURS: usize = 6;
+// Change in average POP, in percentage points, that counts as a trend
+const TREND_POP_CHANGE: f32 = 15.0;
+// Temperature drop (°C) that counts as worsening on its own
+const TREND_TEMP_DROP: f32 = 3.0;
+
+/// Where the next few hours are heading, compared to the hours just ahead
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeatherTrend {
+    Improving,
+    Worsening,
+    Stable,
+}
+
+impl WeatherTrend {
+    pub fn arrow(self) -> &'static str {
+        match self {
+            Self::Improving => "📈",
+            Self::Worsening => "📉",
+            Self::Stable => "➡️",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CurrentConditions {
     pub temperature: f32,
     pub condition: String,
-    pub humidity: Option<u8>, 
-    pub pressure: Option<f32>,
-    pub visibility: Option<f32>,
-    pub wind_speed: Option<u32>,
-    pub wind_direction: Option<String>,
-    pub wind_chill: Option<f32>,
+    pub icon: String,
+    pub humidity: u32,
+    pub wind_speed: u32,
+    pub wind_direction: String,
+    pub wind_gust: Option<u32>,
+    pub wind_chill: Option<i32>,
+    #[serde(default)]
     pub humidex: Option<f32>,
+    #[serde(default)]
+    pub uv_index: Option<u32>,
+    pub pressure: f32,
+    pub pressure_tendency: Option<String>,
+    pub dewpoint: f32,
+    pub visibility: Option<f32>,
+    pub station: String,
+    pub air_quality: Option<AirQuality>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
-pub struct DailyForecast {
-    pub day_name: String,
-    pub high: Option<i32>,
-    pub low: Option<i32>,
-    pub summary: String,
-    pub pop: Option<u32>, // Probability of precipitation
-    pub icon: String,
+// Placeholder state: NaN temperature so "no data" never renders as 0°C
+impl Default for CurrentConditions {
+    fn default() -> Self {
+        Self {
+            temperature: f32::NAN,
+            condition: "No data".to_string(),
+            icon: String::new(),
+            humidity: 0,
+            wind_speed: 0,
+            wind_direction: String::new(),
+            wind_gust: None,
+            wind_chill: None,
+            humidex: None,
+            uv_index: None,
+            pressure: 0.0,
+            pressure_tendency: None,
+            dewpoint: 0.0,
+            visibility: None,
+            station: String::new(),
+            air_quality: None,
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
-pub struct WeatherWarning {
-    pub warning_type: String,
-    pub priority: String,
-    pub description: String,
+/// Environment Canada's humidex discomfort bands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HumidexComfortLevel {
+    Comfortable,
+    SomewhatUncomfortable,
+    Uncomfortable,
+    VeryUncomfortable,
+    Dangerous,
+}
+
+impl HumidexComfortLevel {
+    pub fn from_humidex(humidex: f32) -> Self {
+        match humidex.round() as i32 {
+            ..=29 => Self::Comfortable,
+            30..=39 => Self::SomewhatUncomfortable,
+            40..=45 => Self::Uncomfortable,
+            46..=54 => Self::VeryUncomfortable,
+            _ => Self::Dangerous,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Comfortable => "Comfortable",
+            Self::SomewhatUncomfortable => "Some discomfort",
+            Self::Uncomfortable => "Great discomfort",
+            Self::VeryUncomfortable => "Avoid exertion",
+            Self::Dangerous => "Dangerous",
+        }
+    }
+}
+
+/// Environment Canada's wind chill frostbite risk bands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindChillWarning {
+    Low,
+    Moderate,
+    High,
+    VeryHigh,
+    Extreme,
+}
+
+impl WindChillWarning {
+    pub fn from_wind_chill(wind_chill: i32) -> Self {
+        match wind_chill {
+            -9.. => Self::Low,
+            -27..=-10 => Self::Moderate,
+            -39..=-28 => Self::High,
+            -47..=-40 => Self::VeryHigh,
+            _ => Self::Extreme,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Low => "Low risk",
+            Self::Moderate => "Moderate risk",
+            Self::High => "Frostbite risk",
+            Self::VeryHigh => "High frostbite risk",
+            Self::Extreme => "Extreme frostbite risk",
+        }
+    }
+}
+
+/// Whichever of humidex or wind chill is being reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComfortLevel {
+    Humidex(HumidexComfortLevel),
+    WindChill(WindChillWarning),
+}
+
+impl ComfortLevel {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Humidex(level) => level.label(),
+            Self::WindChill(level) => level.label(),
+        }
+    }
 }
 
 impl CurrentConditions {
+    /// Environment Canada reports wind chill in winter and humidex in summer,
+    /// so whichever is present says which scale applies
+    pub fn feels_like_level(&self) -> Option<ComfortLevel> {
+        self.wind_chill
+            .map(|wc| ComfortLevel::WindChill(WindChillWarning::from_wind_chill(wc)))
+            .or_else(|| self.humidex.map(|h| ComfortLevel::Humidex(HumidexComfortLevel::from_humidex(h))))
+    }
+
+    /// Wind chill in winter, humidex in summer, otherwise the air temperature
     pub fn feels_like(&self) -> f32 {
         self.wind_chill
+            .map(|wc| wc as f32)
             .or(self.humidex)
             .unwrap_or(self.temperature)
     }
-    
+
+    /// False for the default placeholder (NaN temperature)
+    pub fn has_data(&self) -> bool {
+        !self.temperature.is_nan()
+    }
+
     pub fn wind_description(&self) -> String {
-        match (&self.wind_direction, self.wind_speed) {
-            (Some(dir), Some(speed)) => format!("{} {} km/h", dir, speed),
-            (Some(dir), None) => dir.clone(),
-            (None, Some(speed)) => format!("{} km/h", speed),
-            (None, None) => "Calm".to_string(),
+        match (self.wind_direction.is_empty(), self.wind_speed) {
+            (_, 0) => "Calm".to_string(),
+            (true, speed) => format!("{} km/h", speed),
+            (false, speed) => format!("{} {} km/h", self.wind_direction, speed),
         }
     }
 }
 
-impl DailyForecast {
-    pub fn get_emoji(summary: &str) -> String {
-        let s = summary.to_lowercase();
-        
-        if s.contains("sunny") || s.contains("clear") {
-            "☀️".to_string()
-        } else if s.contains("partly cloudy") || s.contains("mix") {
-            "⛅".to_string()
-        } else if s.contains("cloud") {
-            "☁️".to_string()
-        } else if s.contains("rain") || s.contains("shower") {
-            "🌧️".to_string()
-        } else if s.contains("snow") {
-            "🌨️".to_string()
-        } else if s.contains("storm") {
-            "⛈️".to_string()
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AirQuality {
+    pub index: f32,
+    pub category: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeatherWarning {
+    pub description: String,
+    pub alert_level: String,
+    pub url: String,
+    // "warning", "watch", "advisory" or "statement"
+    #[serde(default)]
+    pub warning_type: String,
+    // "high", "medium" or "low", derived from the warning type
+    #[serde(default)]
+    pub priority: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HourlyForecast {
+    pub time: String,
+    pub temperature: i32,
+    pub condition: String,
+    pub pop: u32,
+    pub icon: String,
+    pub wind_speed: u32,
+    pub wind_direction: String,
+    pub wind_chill: Option<i32>,
+    /// Local time the forecast hour starts, for range lookups
+    #[serde(default)]
+    pub parsed_time: Option<NaiveDateTime>,
+    /// What falls, when the condition names any precipitation
+    #[serde(default)]
+    pub precipitation_type: Option<PrecipitationType>,
+}
+
+/// Kind of precipitation named in a forecast condition. Around 0°C it's the
+/// difference between wet bins and bins frozen to the curb.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PrecipitationType {
+    Rain,
+    FreezingRain,
+    Snow,
+    Sleet,
+}
+
+impl PrecipitationType {
+    pub const ALL: [PrecipitationType; 4] = [Self::Rain, Self::FreezingRain, Self::Snow, Self::Sleet];
+
+    // Most specific first, as in `WeatherIcon::from_condition`
+    pub fn from_condition(condition: &str) -> Option<Self> {
+        let c = condition.to_lowercase();
+        let has_rain = c.contains("rain") || c.contains("shower") || c.contains("drizzle");
+        let has_snow = c.contains("snow") || c.contains("flurr");
+
+        if c.contains("freezing") && !c.contains("fog") {
+            Some(Self::FreezingRain)
+        } else if c.contains("ice pellets") || c.contains("sleet") || (has_rain && has_snow) {
+            Some(Self::Sleet)
+        } else if has_snow {
+            Some(Self::Snow)
+        } else if has_rain || c.contains("thunder") {
+            Some(Self::Rain)
         } else {
-            "🌤️".to_string()
+            None
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Rain => "Rain",
+            Self::FreezingRain => "Freezing rain",
+            Self::Snow => "Snow",
+            Self::Sleet => "Sleet",
         }
     }
 }
 
-impl WeatherData {
-    /// Get forecast for a specific day (useful for bin collection days)
-    pub fn get_forecast_for_day(&self, day_name: &str) -> Option<&DailyForecast> {
-        self.forecasts
-            .iter()
-            .find(|f| f.day_name.to_lowercase().contains(&day_name.to_lowercase()))
+impl HourlyForecast {
+    pub fn feels_like(&self) -> f32 {
+        self.wind_chill.unwrap_or(self.temperature) as f32
     }
-    
-    /// Check if there are any severe weather warnings
-    #[allow(dead_code)] // Public API method
-    pub fn has_severe_warnings(&self) -> bool {
-        self.warnings.iter().any(|w| w.priority == "high")
+}
+
+/// Column-wise view of the hourly forecast, in the shape the chart series take
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WeatherHourly {
+    pub times: Vec<String>,
+    pub temperatures: Vec<f64>,
+    pub precipitation: Vec<f64>,
+    pub precipitation_types: Vec<Option<PrecipitationType>>,
+    pub wind_speeds: Vec<f64>,
+}
+
+impl From<&[HourlyForecast]> for WeatherHourly {
+    fn from(forecasts: &[HourlyForecast]) -> Self {
+        Self {
+            times: forecasts.iter().map(|f| f.time.clone()).collect(),
+            temperatures: forecasts.iter().map(|f| f.temperature as f64).collect(),
+            precipitation: forecasts.iter().map(|f| f.pop as f64).collect(),
+            precipitation_types: forecasts.iter().map(|f| f.precipitation_type).collect(),
+            wind_speeds: forecasts.iter().map(|f| f.wind_speed as f64).collect(),
+        }
+    }
+}
+
+impl From<Vec<HourlyForecast>> for WeatherHourly {
+    fn from(forecasts: Vec<HourlyForecast>) -> Self {
+        Self::from(forecasts.as_slice())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyForecast {
+    pub day_name: String,
+    pub high: Option<i32>,
+    pub low: Option<i32>,
+    pub summary: String,
+    pub pop: Option<u32>,
+    pub icon: String,
+    pub uv_index: Option<String>,
+    pub wind_chill: Option<String>,
+    pub wind_summary: Option<String>,
+    /// Feels-like reading nearest 8 AM within the 7-9 AM pickup window, from the
+    /// hourly forecast; only set for days the hourly data reaches
+    #[serde(default)]
+    pub pickup_feels_like: Option<f32>,
+}
+
+// Longest warning description `WeatherWarning`'s Display shows before cutting it off
+const WARNING_DISPLAY_CHARS: usize = 60;
+
+impl fmt::Display for CurrentConditions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.1}°C, {} — Humidity: {}%, Wind: {}",
+            self.temperature,
+            self.condition,
+            self.humidity,
+            self.wind_description()
+        )
+    }
+}
+
+impl fmt::Display for DailyForecast {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} {}", self.day_name, self.icon, self.summary)?;
+        match (self.high, self.low) {
+            (Some(high), Some(low)) => write!(f, ", H:{} L:{}", high, low)?,
+            (Some(high), None) => write!(f, ", H:{}", high)?,
+            (None, Some(low)) => write!(f, ", L:{}", low)?,
+            (None, None) => {}
+        }
+        if let Some(pop) = self.pop {
+            write!(f, ", POP:{}%", pop)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for WeatherWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut description: String = self.description.chars().take(WARNING_DISPLAY_CHARS).collect();
+        if self.description.chars().count() > WARNING_DISPLAY_CHARS {
+            description.push('…');
+        }
+        write!(f, "[{} / {}] {}", self.warning_type, self.priority, description)
+    }
+}
+
+impl DailyForecast {
+    /// Spread between the high and low, when both are forecast
+    pub fn temperature_range(&self) -> Option<i32> {
+        match (self.high, self.low) {
+            (Some(high), Some(low)) => Some(high - low),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_names_normalise_to_the_short_form() {
+        // Full to short, short to short, any case, trailing dot
+        assert_eq!(normalize_day_name("Monday"), "Mon");
+        assert_eq!(normalize_day_name("Mon"), "Mon");
+        assert_eq!(normalize_day_name("WEDNESDAY"), "Wed");
+        assert_eq!(normalize_day_name("thurs"), "Thu");
+        assert_eq!(normalize_day_name("Fri."), "Fri");
+    }
+
+    #[test]
+    fn night_periods_belong_to_their_day() {
+        assert_eq!(normalize_day_name("Tonight"), "Today");
+        assert_eq!(normalize_day_name("Overnight"), "Today");
+        assert_eq!(normalize_day_name("Today"), "Today");
+        assert_eq!(normalize_day_name("Saturday night"), "Sat");
+        assert_eq!(normalize_day_name("sat night"), "Sat");
+    }
+
+    #[test]
+    fn unknown_day_names_come_back_unchanged() {
+        assert_eq!(normalize_day_name("Someday"), "Someday");
+        assert_eq!(normalize_day_name("Mo"), "Mo");
+        assert_eq!(normalize_day_name(""), "");
+    }
+
+    #[test]
+    fn default_conditions_are_distinguishable_from_zero() {
+        let empty = WeatherData::default();
+        assert_eq!(empty.location, "—");
+        assert_eq!(empty.last_updated, "Never");
+        assert!(!empty.current.has_data());
+        assert_eq!(empty.current.condition, "No data");
+
+        let freezing = CurrentConditions { temperature: 0.0, ..CurrentConditions::default() };
+        assert!(freezing.has_data());
+    }
+
+    fn warning(priority: &str) -> WeatherWarning {
+        WeatherWarning {
+            description: String::new(),
+            alert_level: String::new(),
+            url: String::new(),
+            warning_type: String::new(),
+            priority: priority.to_string(),
+        }
+    }
+
+    #[test]
+    fn alerts_are_counted_and_only_high_priority_is_severe() {
+        let mut weather = WeatherData::default();
+        assert_eq!(weather.active_alerts_count(), 0);
+        assert!(!weather.has_severe_warnings());
+
+        weather.warnings = vec![warning("low"), warning("medium")];
+        assert_eq!(weather.active_alerts_count(), 2);
+        assert!(!weather.has_severe_warnings());
+
+        weather.warnings.push(warning("high"));
+        assert!(weather.has_severe_warnings());
+    }
+
+    #[test]
+    fn warning_line_shows_type_priority_and_a_shortened_description() {
+        let short = WeatherWarning {
+            description: "Snowfall warning in effect".to_string(),
+            warning_type: "warning".to_string(),
+            ..warning("high")
+        };
+        assert_eq!(short.to_string(), "[warning / high] Snowfall warning in effect");
+
+        let long = WeatherWarning { description: "x".repeat(WARNING_DISPLAY_CHARS + 1), ..short };
+        assert!(long.to_string().ends_with(&format!("{}…", "x".repeat(WARNING_DISPLAY_CHARS))));
+    }
+
+    fn hour(time: &str, temperature: i32, pop: u32, condition: &str) -> HourlyForecast {
+        HourlyForecast {
+            time: time.to_string(),
+            temperature,
+            condition: condition.to_string(),
+            pop,
+            icon: String::new(),
+            wind_speed: 10,
+            wind_direction: "NW".to_string(),
+            wind_chill: None,
+            parsed_time: None,
+            precipitation_type: PrecipitationType::from_condition(condition),
+        }
+    }
+
+    #[test]
+    fn hourly_series_has_one_column_entry_per_forecast() {
+        let forecasts = [hour("14:00", -2, 40, "Flurries"), hour("15:00", 1, 0, "Cloudy")];
+        let series = WeatherHourly::from(&forecasts[..]);
+
+        assert_eq!(series.times, ["14:00", "15:00"]);
+        assert_eq!(series.temperatures, [-2.0, 1.0]);
+        assert_eq!(series.precipitation, [40.0, 0.0]);
+        assert_eq!(series.precipitation_types, [Some(PrecipitationType::Snow), None]);
+        assert_eq!(series.wind_speeds, [10.0, 10.0]);
+        assert_eq!(WeatherHourly::from(Vec::new()), WeatherHourly::default());
+    }
+
+    #[test]
+    fn precipitation_type_from_condition() {
+        let cases = [
+            ("Chance of showers", Some(PrecipitationType::Rain)),
+            ("Periods of drizzle", Some(PrecipitationType::Rain)),
+            ("Risk of thunderstorms", Some(PrecipitationType::Rain)),
+            ("Light snow", Some(PrecipitationType::Snow)),
+            ("Chance of flurries", Some(PrecipitationType::Snow)),
+            ("Rain mixed with snow", Some(PrecipitationType::Sleet)),
+            ("Ice pellets", Some(PrecipitationType::Sleet)),
+            ("Freezing rain", Some(PrecipitationType::FreezingRain)),
+            ("Freezing fog", None),
+            ("Mainly sunny", None),
+        ];
+
+        for (condition, expected) in cases {
+            assert_eq!(PrecipitationType::from_condition(condition), expected, "{}", condition);
+        }
+    }
+
+    #[test]
+    fn precipitation_types_have_distinct_labels() {
+        let labels: std::collections::HashSet<_> = PrecipitationType::ALL.iter().map(|p| p.label()).collect();
+        assert_eq!(labels.len(), PrecipitationType::ALL.len());
+        assert_eq!(PrecipitationType::FreezingRain.label(), "Freezing rain");
+    }
+
+    #[test]
+    fn humidex_bands_round_at_the_edges() {
+        assert_eq!(HumidexComfortLevel::from_humidex(29.4), HumidexComfortLevel::Comfortable);
+        assert_eq!(HumidexComfortLevel::from_humidex(29.5), HumidexComfortLevel::SomewhatUncomfortable);
+        assert_eq!(HumidexComfortLevel::from_humidex(40.0), HumidexComfortLevel::Uncomfortable);
+        assert_eq!(HumidexComfortLevel::from_humidex(46.0), HumidexComfortLevel::VeryUncomfortable);
+        assert_eq!(HumidexComfortLevel::from_humidex(55.0), HumidexComfortLevel::Dangerous);
+    }
+
+    #[test]
+    fn wind_chill_bands() {
+        assert_eq!(WindChillWarning::from_wind_chill(-9), WindChillWarning::Low);
+        assert_eq!(WindChillWarning::from_wind_chill(-10), WindChillWarning::Moderate);
+        assert_eq!(WindChillWarning::from_wind_chill(-28), WindChillWarning::High);
+        assert_eq!(WindChillWarning::from_wind_chill(-40), WindChillWarning::VeryHigh);
+        assert_eq!(WindChillWarning::from_wind_chill(-48), WindChillWarning::Extreme);
+    }
+
+    #[test]
+    fn feels_like_level_prefers_wind_chill_then_humidex() {
+        let conditions = |wind_chill, humidex| CurrentConditions { wind_chill, humidex, ..CurrentConditions::default() };
+
+        assert_eq!(
+            conditions(Some(-30), Some(35.0)).feels_like_level(),
+            Some(ComfortLevel::WindChill(WindChillWarning::High)),
+        );
+        assert_eq!(
+            conditions(None, Some(35.0)).feels_like_level(),
+            Some(ComfortLevel::Humidex(HumidexComfortLevel::SomewhatUncomfortable)),
+        );
+        assert_eq!(conditions(None, None).feels_like_level(), None);
+        assert_eq!(ComfortLevel::WindChill(WindChillWarning::High).label(), "Frostbite risk");
     }
 }
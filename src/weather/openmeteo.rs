@@ -0,0 +1,334 @@
+// src/weather/openmeteo.rs
+//
+// Open-Meteo fallback for when Environment Canada can't be reached. It sends
+// permissive CORS headers and needs no key, but only covers the basics: no
+// warnings, AQHI or pressure tendency.
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use gloo_net::http::Request;
+use serde::Deserialize;
+
+use crate::utils::logger::{log_weather, LogLevel};
+use crate::utils::{SystemTimeProvider, TimeProvider};
+use crate::weather::models::{
+    CurrentConditions, DailyForecast, HourlyForecast, PrecipitationType, WeatherData,
+    FALLBACK_LOCATION_PREFIX,
+};
+use crate::weather::icons::WeatherIcon;
+
+const OPEN_METEO_URL: &str = "https://api.open-meteo.com/v1/forecast";
+const CURRENT_FIELDS: &str = "temperature_2m,relative_humidity_2m,apparent_temperature,weather_code,wind_speed_10m,wind_direction_10m,wind_gusts_10m,pressure_msl,dew_point_2m";
+const HOURLY_FIELDS: &str = "temperature_2m,weather_code,precipitation_probability,wind_speed_10m,wind_direction_10m";
+const DAILY_FIELDS: &str = "weather_code,temperature_2m_max,temperature_2m_min,precipitation_probability_max,sunrise,sunset,uv_index_max";
+// Open-Meteo's local timestamps have no seconds or offset
+const TIME_FORMAT: &str = "%Y-%m-%dT%H:%M";
+
+#[derive(Debug, Deserialize)]
+struct OpenMeteoResponse {
+    current: OpenMeteoCurrent,
+    hourly: OpenMeteoHourly,
+    daily: OpenMeteoDaily,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenMeteoCurrent {
+    time: String,
+    temperature_2m: f32,
+    relative_humidity_2m: f32,
+    apparent_temperature: f32,
+    weather_code: u32,
+    wind_speed_10m: f32,
+    wind_direction_10m: f32,
+    wind_gusts_10m: Option<f32>,
+    pressure_msl: f32,
+    dew_point_2m: f32,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenMeteoHourly {
+    time: Vec<String>,
+    temperature_2m: Vec<Option<f32>>,
+    weather_code: Vec<Option<u32>>,
+    precipitation_probability: Vec<Option<u32>>,
+    wind_speed_10m: Vec<Option<f32>>,
+    wind_direction_10m: Vec<Option<f32>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenMeteoDaily {
+    time: Vec<String>,
+    weather_code: Vec<Option<u32>>,
+    temperature_2m_max: Vec<Option<f32>>,
+    temperature_2m_min: Vec<Option<f32>>,
+    precipitation_probability_max: Vec<Option<u32>>,
+    sunrise: Vec<String>,
+    sunset: Vec<String>,
+    uv_index_max: Vec<Option<f32>>,
+}
+
+pub async fn fetch_openmeteo_fallback(lat: f64, lon: f64) -> Result<WeatherData, String> {
+    log_weather(&format!("Falling back to Open-Meteo for {:.2}, {:.2}", lat, lon), LogLevel::Warn);
+
+    let url = format!(
+        "{}?latitude={}&longitude={}&current={}&hourly={}&daily={}&timezone=auto&forecast_days=7",
+        OPEN_METEO_URL, lat, lon, CURRENT_FIELDS, HOURLY_FIELDS, DAILY_FIELDS
+    );
+
+    let response = Request::get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Open-Meteo network error: {:?}", e))?;
+
+    if !response.ok() {
+        return Err(format!("Open-Meteo HTTP {}: {}", response.status(), response.status_text()));
+    }
+
+    let data = response
+        .json::<OpenMeteoResponse>()
+        .await
+        .map_err(|e| format!("Open-Meteo JSON error: {:?}", e))?;
+
+    Ok(to_weather_data(data, lat, lon, &SystemTimeProvider))
+}
+
+fn to_weather_data(data: OpenMeteoResponse, lat: f64, lon: f64, time: &impl TimeProvider) -> WeatherData {
+    let fetched_at = time.now();
+    let current = &data.current;
+    let condition = describe_weather_code(current.weather_code).to_string();
+    let temperature = current.temperature_2m;
+    // Same rule Environment Canada uses for when wind chill or humidex is reported
+    let feels_like = current.apparent_temperature;
+    let (wind_chill, humidex) = if temperature <= 0.0 && feels_like < temperature {
+        (Some(feels_like.round() as i32), None)
+    } else if temperature >= 20.0 && feels_like > temperature {
+        (None, Some(feels_like.round()))
+    } else {
+        (None, None)
+    };
+    let uv_index = data.daily.uv_index_max.first()
+        .copied()
+        .flatten()
+        .map(|uv| uv.round() as u32);
+
+    let current_conditions = CurrentConditions {
+        temperature,
+        icon: WeatherIcon::from_condition(&condition).to_string(),
+        condition,
+        humidity: current.relative_humidity_2m.round() as u32,
+        wind_speed: current.wind_speed_10m.round() as u32,
+        wind_direction: compass_direction(current.wind_direction_10m).to_string(),
+        wind_gust: current.wind_gusts_10m.map(|g| g.round() as u32),
+        wind_chill,
+        humidex,
+        uv_index,
+        // hPa to kPa, to match Environment Canada
+        pressure: current.pressure_msl / 10.0,
+        pressure_tendency: None,
+        dewpoint: current.dew_point_2m,
+        visibility: None,
+        station: "Open-Meteo".to_string(),
+        air_quality: None,
+    };
+
+    let now = NaiveDateTime::parse_from_str(&current.time, TIME_FORMAT)
+        .unwrap_or_else(|_| fetched_at.naive_local());
+
+    let mut weather = WeatherData {
+        current: current_conditions,
+        hourly: hourly_forecasts(&data.hourly, now),
+        daily: daily_forecasts(&data.daily, now.date()),
+        warnings: Vec::new(),
+        sunrise: first_time_of_day(&data.daily.sunrise),
+        sunset: first_time_of_day(&data.daily.sunset),
+        last_updated: fetched_at.to_rfc3339(),
+        location: format!("{} ({:.2}, {:.2})", FALLBACK_LOCATION_PREFIX, lat, lon),
+    };
+    weather.fill_pickup_feels_like();
+    weather
+}
+
+fn hourly_forecasts(hourly: &OpenMeteoHourly, now: NaiveDateTime) -> Vec<HourlyForecast> {
+    let current_hour = now.with_minute(0).unwrap_or(now);
+
+    hourly.time.iter()
+        .enumerate()
+        .filter_map(|(i, time)| {
+            let parsed_time = NaiveDateTime::parse_from_str(time, TIME_FORMAT).ok()?;
+            if parsed_time < current_hour {
+                return None;
+            }
+            let condition = describe_weather_code(hourly.weather_code.get(i).copied().flatten()?).to_string();
+            Some(HourlyForecast {
+                time: parsed_time.format("%-I:%M %p").to_string(),
+                temperature: hourly.temperature_2m.get(i).copied().flatten()?.round() as i32,
+                icon: WeatherIcon::from_condition(&condition).to_string(),
+                precipitation_type: PrecipitationType::from_condition(&condition),
+                condition,
+                pop: hourly.precipitation_probability.get(i).copied().flatten().unwrap_or(0),
+                wind_speed: hourly.wind_speed_10m.get(i).copied().flatten().unwrap_or(0.0).round() as u32,
+                wind_direction: hourly.wind_direction_10m.get(i)
+                    .copied()
+                    .flatten()
+                    .map(|d| compass_direction(d).to_string())
+                    .unwrap_or_default(),
+                wind_chill: None,
+                parsed_time: Some(parsed_time),
+            })
+        })
+        .take(24)
+        .collect()
+}
+
+fn daily_forecasts(daily: &OpenMeteoDaily, today: NaiveDate) -> Vec<DailyForecast> {
+    daily.time.iter()
+        .enumerate()
+        .filter_map(|(i, date)| {
+            let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+            let summary = describe_weather_code(daily.weather_code.get(i).copied().flatten()?).to_string();
+            // Environment Canada calls the current day "Today"
+            let day_name = if date == today {
+                "Today".to_string()
+            } else {
+                date.format("%A").to_string()
+            };
+            Some(DailyForecast {
+                day_name,
+                high: daily.temperature_2m_max.get(i).copied().flatten().map(|t| t.round() as i32),
+                low: daily.temperature_2m_min.get(i).copied().flatten().map(|t| t.round() as i32),
+                icon: WeatherIcon::from_condition(&summary).to_string(),
+                summary,
+                pop: daily.precipitation_probability_max.get(i).copied().flatten(),
+                uv_index: daily.uv_index_max.get(i).copied().flatten().map(|uv| format!("{:.0}", uv)),
+                wind_chill: None,
+                wind_summary: None,
+                pickup_feels_like: None,
+            })
+        })
+        .collect()
+}
+
+// Today's entry of a daily sunrise/sunset column, already in local time
+fn first_time_of_day(times: &[String]) -> Option<NaiveTime> {
+    NaiveDateTime::parse_from_str(times.first()?, TIME_FORMAT)
+        .ok()
+        .map(|t| t.time())
+}
+
+fn compass_direction(degrees: f32) -> &'static str {
+    const POINTS: [&str; 8] = ["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+    let index = ((degrees.rem_euclid(360.0) + 22.5) / 45.0) as usize % 8;
+    POINTS[index]
+}
+
+// WMO weather interpretation codes, worded like Environment Canada conditions
+fn describe_weather_code(code: u32) -> &'static str {
+    match code {
+        0 => "Clear",
+        1 => "Mainly sunny",
+        2 => "Partly cloudy",
+        3 => "Cloudy",
+        45 | 48 => "Fog",
+        56 | 57 => "Freezing drizzle",
+        51..=55 => "Drizzle",
+        66 | 67 => "Freezing rain",
+        61..=65 => "Rain",
+        71..=77 => "Snow",
+        80..=82 => "Showers",
+        85 | 86 => "Flurries",
+        95..=99 => "Thunderstorm",
+        _ => "Unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Local, TimeZone};
+    use crate::utils::MockTimeProvider;
+
+    const RESPONSE: &str = r#"{
+        "current": {
+            "time": "2025-01-15T08:00", "temperature_2m": -6.4, "relative_humidity_2m": 71,
+            "apparent_temperature": -12.6, "weather_code": 73, "wind_speed_10m": 18.2,
+            "wind_direction_10m": 292, "wind_gusts_10m": 35.0, "pressure_msl": 1021.0, "dew_point_2m": -10.5
+        },
+        "hourly": {
+            "time": ["2025-01-15T07:00", "2025-01-15T08:00", "2025-01-15T09:00"],
+            "temperature_2m": [-6.8, -6.4, null],
+            "weather_code": [71, 73, 3],
+            "precipitation_probability": [40, 60, 10],
+            "wind_speed_10m": [15.0, 18.2, 20.0],
+            "wind_direction_10m": [290, 292, 300]
+        },
+        "daily": {
+            "time": ["2025-01-15", "2025-01-16"],
+            "weather_code": [73, 42],
+            "temperature_2m_max": [-4.6, -1.2],
+            "temperature_2m_min": [-11.4, -8.0],
+            "precipitation_probability_max": [80, null],
+            "sunrise": ["2025-01-15T07:48", "2025-01-16T07:47"],
+            "sunset": ["2025-01-15T17:03", "2025-01-16T17:04"],
+            "uv_index_max": [1.2, 1.5]
+        }
+    }"#;
+
+    fn parse(json: &str) -> WeatherData {
+        let data = serde_json::from_str::<OpenMeteoResponse>(json).unwrap();
+        let clock = MockTimeProvider(Local.with_ymd_and_hms(2025, 1, 15, 8, 5, 0).unwrap());
+        to_weather_data(data, 43.65, -79.38, &clock)
+    }
+
+    #[test]
+    fn current_conditions_are_mapped() {
+        let weather = parse(RESPONSE);
+        let current = &weather.current;
+
+        assert_eq!(current.condition, "Snow");
+        assert_eq!((current.humidity, current.wind_speed, current.wind_direction.as_str()), (71, 18, "W"));
+        assert_eq!(current.wind_gust, Some(35));
+        // Colder than the air temperature below freezing, so it's wind chill
+        assert_eq!((current.wind_chill, current.humidex), (Some(-13), None));
+        assert_eq!(current.pressure, 102.1);
+        assert_eq!(current.uv_index, Some(1));
+        assert!(weather.is_fallback());
+        assert_eq!(weather.location, "Open-Meteo fallback (43.65, -79.38)");
+    }
+
+    #[test]
+    fn fetch_time_comes_from_the_clock() {
+        let weather = parse(RESPONSE);
+
+        assert_eq!(weather.last_updated, Local.with_ymd_and_hms(2025, 1, 15, 8, 5, 0).unwrap().to_rfc3339());
+        assert_eq!(weather.sunrise, NaiveTime::from_hms_opt(7, 48, 0));
+        assert_eq!(weather.sunset, NaiveTime::from_hms_opt(17, 3, 0));
+    }
+
+    #[test]
+    fn forecasts_start_at_the_current_hour() {
+        let weather = parse(RESPONSE);
+
+        // 07:00 is past; 09:00 has no temperature
+        assert_eq!(weather.hourly.len(), 1);
+        assert_eq!(weather.hourly[0].time, "8:00 AM");
+        assert_eq!(weather.hourly[0].precipitation_type, Some(PrecipitationType::Snow));
+        assert_eq!(weather.daily[0].day_name, "Today");
+        assert_eq!((weather.daily[0].high, weather.daily[0].low, weather.daily[0].pop), (Some(-5), Some(-11), Some(80)));
+        assert_eq!(weather.daily[1].day_name, "Thursday");
+    }
+
+    #[test]
+    fn unknown_weather_codes_fall_back() {
+        assert_eq!(describe_weather_code(42), "Unknown");
+        assert_eq!(parse(RESPONSE).daily[1].summary, "Unknown");
+        assert_eq!(WeatherIcon::from_condition(describe_weather_code(42)), WeatherIcon::Default);
+    }
+
+    #[test]
+    fn weather_codes_read_like_environment_canada() {
+        assert_eq!(describe_weather_code(0), "Clear");
+        assert_eq!(describe_weather_code(2), "Partly cloudy");
+        assert_eq!(describe_weather_code(56), "Freezing drizzle");
+        assert_eq!(describe_weather_code(66), "Freezing rain");
+        assert_eq!(describe_weather_code(81), "Showers");
+        assert_eq!(describe_weather_code(96), "Thunderstorm");
+    }
+}
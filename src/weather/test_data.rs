@@ -1,22 +1,34 @@
 // src/weather/test_data.rs
+#[cfg(test)]
+use std::{cell::Cell, rc::Rc};
+
+#[cfg(test)]
+use crate::weather::client::WeatherClient;
 use crate::weather::models::*;
 
-#[allow(dead_code)] // Useful for testing and development
+/// Sample data shown when offline, and in debug builds when a fetch fails
 pub fn get_mock_weather() -> WeatherData {
     WeatherData {
-        location: "Toronto".to_string(),
         current: CurrentConditions {
             temperature: 8.0,
             condition: "Mainly Cloudy".to_string(),
-            humidity: Some(68),
-            pressure: Some(101.3),
-            visibility: Some(24.0),
-            wind_speed: Some(15),
-            wind_direction: Some("SW".to_string()),
-            wind_chill: Some(6.0),
+            icon: "☁️".to_string(),
+            humidity: 68,
+            wind_speed: 15,
+            wind_direction: "SW".to_string(),
+            wind_gust: None,
+            wind_chill: Some(6),
             humidex: None,
+            uv_index: Some(1),
+            pressure: 101.3,
+            pressure_tendency: Some("rising".to_string()),
+            dewpoint: 2.4,
+            visibility: Some(24.0),
+            station: "Mock Data".to_string(),
+            air_quality: None,
         },
-        forecasts: vec![
+        hourly: vec![],
+        daily: vec![
             DailyForecast {
                 day_name: "Today".to_string(),
                 high: Some(12),
@@ -24,6 +36,10 @@ pub fn get_mock_weather() -> WeatherData {
                 summary: "Cloudy periods".to_string(),
                 pop: Some(30),
                 icon: "☁️".to_string(),
+                uv_index: None,
+                wind_chill: None,
+                wind_summary: None,
+                pickup_feels_like: None,
             },
             DailyForecast {
                 day_name: "Monday".to_string(),
@@ -32,6 +48,10 @@ pub fn get_mock_weather() -> WeatherData {
                 summary: "Sunny".to_string(),
                 pop: Some(10),
                 icon: "☀️".to_string(),
+                uv_index: None,
+                wind_chill: None,
+                wind_summary: None,
+                pickup_feels_like: None,
             },
             DailyForecast {
                 day_name: "Tuesday".to_string(),
@@ -40,9 +60,57 @@ pub fn get_mock_weather() -> WeatherData {
                 summary: "Mix sun and cloud".to_string(),
                 pop: Some(20),
                 icon: "⛅".to_string(),
+                uv_index: None,
+                wind_chill: None,
+                wind_summary: None,
+                pickup_feels_like: None,
             },
         ],
         warnings: vec![],
-        last_updated: "Mock Data".to_string(),
+        sunrise: None,
+        sunset: None,
+        last_updated: String::new(),
+        location: "Toronto".to_string(),
+    }
+}
+
+/// Serves `get_mock_weather()` without touching the network, after failing
+/// a set number of fetches first
+#[cfg(test)]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MockWeatherClient {
+    failures_left: Rc<Cell<u32>>,
+    attempts: Rc<Cell<u32>>,
+}
+
+#[cfg(test)]
+impl MockWeatherClient {
+    pub fn failing(times: u32) -> Self {
+        Self {
+            failures_left: Rc::new(Cell::new(times)),
+            ..Self::default()
+        }
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts.get()
+    }
+}
+
+#[cfg(test)]
+impl WeatherClient for MockWeatherClient {
+    fn identifier(&self) -> String {
+        "mock".to_string()
+    }
+
+    async fn fetch_weather(&self) -> Result<WeatherData, String> {
+        self.attempts.set(self.attempts.get() + 1);
+        match self.failures_left.get() {
+            0 => Ok(get_mock_weather()),
+            left => {
+                self.failures_left.set(left - 1);
+                Err(format!("mock failure {}", self.attempts.get()))
+            }
+        }
     }
 }